@@ -109,17 +109,17 @@ impl AbstractTree for Block {
                 })
                 .unwrap_or(final_result.into_inner().map_err(|_| RwLockError)?)
         } else {
-            for (index, statement) in self.statements.iter().enumerate() {
-                if statement.is_return() {
-                    return statement.run(_source, _context);
-                }
+            let mut value = Value::none();
 
-                if index == self.statements.len() - 1 {
-                    return statement.run(_source, _context);
+            for statement in &self.statements {
+                value = statement.run(_source, _context)?;
+
+                if statement.is_return() {
+                    break;
                 }
             }
 
-            Ok(Value::none())
+            Ok(value)
         }
     }
 
@@ -64,7 +64,15 @@ impl AbstractTree for EnumPattern {
 }
 
 impl Format for EnumPattern {
-    fn format(&self, _output: &mut String, _indent_level: u8) {
-        todo!()
+    fn format(&self, output: &mut String, indent_level: u8) {
+        self.name.format(output, indent_level);
+        output.push_str("::");
+        self.variant.format(output, indent_level);
+
+        if let Some(identifier) = &self.inner_identifier {
+            output.push('(');
+            identifier.format(output, indent_level);
+            output.push(')');
+        }
     }
 }
@@ -2,9 +2,9 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    error::{RuntimeError, SyntaxError, ValidationError},
-    AbstractTree, Block, Context, Expression, Format, Identifier, SourcePosition, SyntaxNode, Type,
-    Value,
+    error::{rw_lock_error::RwLockError, RuntimeError, SyntaxError, ValidationError},
+    AbstractTree, Block, Context, Expression, Format, Identifier, List, SourcePosition, SyntaxNode,
+    Type, Value,
 };
 
 /// Abstract representation of a for loop statement.
@@ -20,6 +20,34 @@ pub struct For {
     context: Context,
 }
 
+impl For {
+    /// Return the number of allowances that validation gave the item variable.
+    fn item_allowances(&self) -> Result<usize, RwLockError> {
+        if let Some((_value_data, counter)) = self.context.inner()?.get(&self.item_id) {
+            Ok(counter.get_counts()?.0)
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Set the item variable for the next iteration.
+    ///
+    /// The variable is replaced rather than updated so that each iteration
+    /// starts with a fresh usage counter. Otherwise the uses from previous
+    /// iterations would exhaust its allowances and the context would drop it
+    /// mid-loop.
+    fn set_item(&self, value: Value, allowances: usize) -> Result<(), RwLockError> {
+        self.context.unset(&self.item_id)?;
+        self.context.set_value(self.item_id.clone(), value)?;
+
+        for _ in 0..allowances {
+            self.context.add_allowance(&self.item_id)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl AbstractTree for For {
     fn from_syntax(node: SyntaxNode, source: &str, context: &Context) -> Result<Self, SyntaxError> {
         SyntaxError::expect_syntax_node("for", node)?;
@@ -70,11 +98,20 @@ impl AbstractTree for For {
             Type::Any => Type::Any,
             Type::Collection => Type::Any,
             Type::List => Type::Any,
-            Type::ListOf(_) => todo!(),
-            Type::ListExact(_) => todo!(),
-            Type::Map(_) => todo!(),
-            Type::String => todo!(),
-            Type::Range => todo!(),
+            Type::ListOf(item_type) => *item_type,
+            Type::ListExact(item_types) => {
+                let mut item_types = item_types.into_iter();
+                let first = item_types.next().unwrap_or(Type::Any);
+
+                if item_types.all(|item_type| item_type == first) {
+                    first
+                } else {
+                    Type::Any
+                }
+            }
+            Type::Map(_) => Type::ListOf(Box::new(Type::Any)),
+            Type::String => Type::String,
+            Type::Range => Type::Integer,
             _ => {
                 return Err(ValidationError::TypeCheck {
                     expected: Type::Collection,
@@ -95,39 +132,61 @@ impl AbstractTree for For {
         self.context.inherit_all_from(context)?;
 
         let expression_run = self.collection.run(source, context)?;
-        let key = &self.item_id;
+        let allowances = self.item_allowances()?;
 
         if let Value::Range(range) = expression_run {
             if self.is_async {
-                range.into_par_iter().try_for_each(|integer| {
-                    self.context.add_allowance(key)?;
-                    self.context
-                        .set_value(key.clone(), Value::Integer(integer))?;
+                ignore_break(range.into_par_iter().try_for_each(|integer| {
+                    self.set_item(Value::Integer(integer), allowances)?;
                     self.block.run(source, &self.context).map(|_value| ())
-                })?;
+                }))?;
             } else {
                 for i in range {
-                    self.context.add_allowance(key)?;
-                    self.context.set_value(key.clone(), Value::Integer(i))?;
-                    self.block.run(source, &self.context)?;
+                    self.set_item(Value::Integer(i), allowances)?;
+
+                    match self.block.run(source, &self.context) {
+                        Ok(_) => {}
+                        Err(RuntimeError::Break { .. }) => break,
+                        Err(error) => return Err(error),
+                    }
                 }
             }
 
             return Ok(Value::none());
         }
 
-        if let Value::List(list) = &expression_run {
-            if self.is_async {
-                list.items()?.par_iter().try_for_each(|value| {
-                    self.context.add_allowance(key)?;
-                    self.context.set_value(key.clone(), value.clone())?;
-                    self.block.run(source, &self.context).map(|_value| ())
-                })?;
-            } else {
-                for value in list.items()?.iter() {
-                    self.context.add_allowance(key)?;
-                    self.context.set_value(key.clone(), value.clone())?;
-                    self.block.run(source, &self.context)?;
+        let values = match &expression_run {
+            Value::List(list) => list.items()?.clone(),
+            Value::String(string) => string
+                .chars()
+                .map(|char| Value::string(char.to_string()))
+                .collect(),
+            Value::Map(map) => map
+                .inner()
+                .iter()
+                .map(|(key, value)| {
+                    Value::List(List::with_items(vec![
+                        Value::string(key.inner().as_str()),
+                        value.clone(),
+                    ]))
+                })
+                .collect(),
+            _ => return Ok(Value::none()),
+        };
+
+        if self.is_async {
+            ignore_break(values.par_iter().try_for_each(|value| {
+                self.set_item(value.clone(), allowances)?;
+                self.block.run(source, &self.context).map(|_value| ())
+            }))?;
+        } else {
+            for value in values {
+                self.set_item(value, allowances)?;
+
+                match self.block.run(source, &self.context) {
+                    Ok(_) => {}
+                    Err(RuntimeError::Break { .. }) => break,
+                    Err(error) => return Err(error),
                 }
             }
         }
@@ -136,6 +195,15 @@ impl AbstractTree for For {
     }
 }
 
+/// Treats a `break` in an async loop as the end of the loop. Items that were
+/// already running in parallel may still finish.
+fn ignore_break(result: Result<(), RuntimeError>) -> Result<(), RuntimeError> {
+    match result {
+        Err(RuntimeError::Break { .. }) => Ok(()),
+        result => result,
+    }
+}
+
 impl Format for For {
     fn format(&self, output: &mut String, indent_level: u8) {
         if self.is_async {
@@ -1,10 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    built_in_functions::Callable,
     error::{RuntimeError, SyntaxError, ValidationError},
-    AbstractTree, Context, Expression, Format, Function, FunctionExpression, SourcePosition,
-    SyntaxNode, Type, Value,
+    AbstractTree, Context, Expression, Format, FunctionExpression, SourcePosition, SyntaxNode,
+    Type, Value,
 };
 
 /// A function being invoked and the arguments it is being passed.
@@ -110,7 +109,23 @@ impl AbstractTree for FunctionCall {
             });
         };
 
-        if self.arguments.len() != parameter_types.len() {
+        let variadic_type = if let Some(Type::Variadic(item_type)) = parameter_types.last() {
+            Some(item_type.as_ref())
+        } else {
+            None
+        };
+
+        if variadic_type.is_some() {
+            let minimum = parameter_types.len() - 1;
+
+            if self.arguments.len() < minimum {
+                return Err(ValidationError::ExpectedFunctionArgumentMinimum {
+                    minumum_expected: minimum,
+                    actual: self.arguments.len(),
+                    position: self.syntax_position,
+                });
+            }
+        } else if self.arguments.len() != parameter_types.len() {
             return Err(ValidationError::ExpectedFunctionArgumentAmount {
                 expected: parameter_types.len(),
                 actual: self.arguments.len(),
@@ -121,7 +136,14 @@ impl AbstractTree for FunctionCall {
         for (index, expression) in self.arguments.iter().enumerate() {
             expression.validate(_source, context)?;
 
-            if let Some(expected) = parameter_types.get(index) {
+            let expected = match (parameter_types.get(index), variadic_type) {
+                (Some(Type::Variadic(_)), Some(item_type)) | (None, Some(item_type)) => {
+                    Some(item_type)
+                }
+                (expected, _) => expected,
+            };
+
+            if let Some(expected) = expected {
                 let actual = expression.expected_type(context)?;
 
                 if !expected.accepts(&actual) {
@@ -155,36 +177,33 @@ impl AbstractTree for FunctionCall {
             FunctionExpression::Index(index) => index.run(source, context)?,
         };
         let function = value.as_function()?;
+        let mut arguments = Vec::with_capacity(self.arguments.len());
 
-        match function {
-            Function::BuiltIn(built_in_function) => {
-                let mut arguments = Vec::with_capacity(self.arguments.len());
-
-                for expression in &self.arguments {
-                    let value = expression.run(source, context)?;
-
-                    arguments.push(value);
-                }
-
-                built_in_function.call(&arguments, source, context)
-            }
-            Function::ContextDefined(function_node) => {
-                let call_context = Context::with_variables_from(function_node.context())?;
-
-                call_context.inherit_from(context)?;
-
-                let parameter_expression_pairs =
-                    function_node.parameters().iter().zip(self.arguments.iter());
-
-                for (identifier, expression) in parameter_expression_pairs {
-                    let value = expression.run(source, context)?;
-
-                    call_context.set_value(identifier.clone(), value)?;
-                }
+        for expression in &self.arguments {
+            let value = expression.run(source, context)?;
 
-                function_node.body().run(source, &call_context)
-            }
+            arguments.push(value);
         }
+
+        function
+            .call(&arguments, source, context)
+            .map_err(|error| match error {
+                RuntimeError::Panic {
+                    message,
+                    position: None,
+                } => RuntimeError::Panic {
+                    message,
+                    position: Some(self.syntax_position),
+                },
+                RuntimeError::AssertFailed {
+                    message,
+                    position: None,
+                } => RuntimeError::AssertFailed {
+                    message,
+                    position: Some(self.syntax_position),
+                },
+                error => error,
+            })
     }
 }
 
@@ -3,9 +3,9 @@ use std::fmt::{self, Display, Formatter};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    error::{RuntimeError, SyntaxError, ValidationError},
-    AbstractTree, Block, Context, Format, Function, Identifier, SourcePosition, SyntaxNode, Type,
-    TypeSpecification, Value,
+    error::{rw_lock_error::RwLockError, RuntimeError, SyntaxError, ValidationError},
+    AbstractTree, Block, Context, ContextMode, Format, Function, Identifier, SourcePosition,
+    SyntaxNode, Type, TypeSpecification, Value,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
@@ -40,6 +40,22 @@ impl FunctionNode {
         &self.context
     }
 
+    /// Return a copy of this function that closes over the variables in
+    /// `context`.
+    ///
+    /// The captured context never drops variables because the uses in the
+    /// function body are counted separately from those in the defining scope.
+    pub fn capture(&self, context: &Context) -> Result<FunctionNode, RwLockError> {
+        let captured_context = Context::new(ContextMode::AllowGarbage);
+
+        captured_context.inherit_all_from(context)?;
+
+        Ok(FunctionNode {
+            context: captured_context,
+            ..self.clone()
+        })
+    }
+
     pub fn return_type(&self) -> &Type {
         match &self.r#type {
             Type::Function {
@@ -105,7 +121,7 @@ impl AbstractTree for FunctionNode {
             return_type,
         } = &self.r#type
         {
-            self.context.inherit_from(context)?;
+            self.context.inherit_all_from(context)?;
 
             for (parameter, r#type) in self.parameters.iter().zip(parameter_types.iter()) {
                 self.context.set_type(parameter.clone(), r#type.clone())?;
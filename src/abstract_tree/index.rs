@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     error::{RuntimeError, SyntaxError, ValidationError},
     AbstractTree, Context, Format, Identifier, IndexExpression, SourcePosition, SyntaxNode, Type,
-    Value,
+    Value, ValueNode,
 };
 
 /// Abstract representation of an index expression.
@@ -36,6 +36,20 @@ impl AbstractTree for Index {
     fn expected_type(&self, context: &Context) -> Result<Type, ValidationError> {
         match self.collection.expected_type(context)? {
             Type::ListOf(item_type) => Ok(*item_type.clone()),
+            Type::ListExact(item_types) => {
+                if let IndexExpression::Value(ValueNode::Integer(index_source)) = &self.index {
+                    let item_type = index_source
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|index| item_types.get(index));
+
+                    if let Some(r#type) = item_type {
+                        return Ok(r#type.clone());
+                    }
+                }
+
+                Ok(Type::Any)
+            }
             Type::Map(map_types_option) => {
                 if let (Some(map_type), IndexExpression::Identifier(identifier)) =
                     (map_types_option, &self.index)
@@ -112,6 +126,21 @@ impl AbstractTree for Index {
 
                 Ok(value.clone())
             }
+            Value::OrderedMap(ordered_map) => {
+                let identifier = if let IndexExpression::Identifier(identifier) = &self.index {
+                    identifier.clone()
+                } else {
+                    Identifier::new(self.index.run(source, context)?.as_string()?)
+                };
+
+                if let Some(value) = ordered_map.get(&identifier)? {
+                    Ok(value)
+                } else {
+                    Err(RuntimeError::ValidationFailure(
+                        ValidationError::VariableIdentifierNotFound(identifier),
+                    ))
+                }
+            }
             Value::String(string) => {
                 let index = self.index.run(source, context)?.as_integer()? as usize;
                 let item = string.chars().nth(index).unwrap_or_default();
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -53,6 +55,14 @@ impl AbstractTree for Logic {
 
     fn run(&self, source: &str, context: &Context) -> Result<Value, RuntimeError> {
         let left = self.left.run(source, context)?;
+
+        // The right side is only evaluated when it can change the result.
+        match self.operator {
+            LogicOperator::And if !left.as_boolean()? => return Ok(Value::Boolean(false)),
+            LogicOperator::Or if left.as_boolean()? => return Ok(Value::Boolean(true)),
+            _ => {}
+        }
+
         let right = self.right.run(source, context)?;
 
         log::info!("RUN logic expression: {left} {} {right}", self.operator);
@@ -74,16 +84,33 @@ impl AbstractTree for Logic {
             }
             LogicOperator::And => left.as_boolean()? && right.as_boolean()?,
             LogicOperator::Or => left.as_boolean()? || right.as_boolean()?,
-            LogicOperator::Greater => left > right,
-            LogicOperator::Less => left < right,
-            LogicOperator::GreaterOrEqual => left >= right,
-            LogicOperator::LessOrEqual => left <= right,
+            LogicOperator::Greater => compare(&left, &right)? == Some(Ordering::Greater),
+            LogicOperator::Less => compare(&left, &right)? == Some(Ordering::Less),
+            LogicOperator::GreaterOrEqual => matches!(
+                compare(&left, &right)?,
+                Some(Ordering::Greater | Ordering::Equal)
+            ),
+            LogicOperator::LessOrEqual => matches!(
+                compare(&left, &right)?,
+                Some(Ordering::Less | Ordering::Equal)
+            ),
         };
 
         Ok(Value::Boolean(result))
     }
 }
 
+/// Compares floats with IEEE 754 semantics, so any comparison with NaN is
+/// unordered. Other values use their total order, which sorts NaN above every
+/// other float.
+fn compare(left: &Value, right: &Value) -> Result<Option<Ordering>, RuntimeError> {
+    if (left.is_float() || right.is_float()) && left.is_number() && right.is_number() {
+        Ok(left.as_number()?.partial_cmp(&right.as_number()?))
+    } else {
+        Ok(Some(left.cmp(right)))
+    }
+}
+
 impl Format for Logic {
     fn format(&self, output: &mut String, indent_level: u8) {
         self.left.format(output, indent_level);
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{RuntimeError, SyntaxError, ValidationError},
+    AbstractTree, Block, Context, Format, SyntaxNode, Type, Value,
+};
+
+/// Abstract representation of a loop.
+///
+/// A loop executes its block repeatedly until a `break` statement runs, even
+/// one nested in an `if` or `match`, and evaluates to the break's value. A
+/// `return` statement directly in the block also ends the loop. A loop without
+/// either runs until an error occurs.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Loop {
+    block: Block,
+}
+
+impl AbstractTree for Loop {
+    fn from_syntax(node: SyntaxNode, source: &str, context: &Context) -> Result<Self, SyntaxError> {
+        SyntaxError::expect_syntax_node("loop_node", node)?;
+
+        let block_node = node.child(1).unwrap();
+        let block = Block::from_syntax(block_node, source, context)?;
+
+        Ok(Loop { block })
+    }
+
+    fn expected_type(&self, context: &Context) -> Result<Type, ValidationError> {
+        self.block.expected_type(context)
+    }
+
+    fn validate(&self, _source: &str, context: &Context) -> Result<(), ValidationError> {
+        log::info!("VALIDATE loop");
+
+        self.block.validate(_source, context)
+    }
+
+    fn run(&self, source: &str, context: &Context) -> Result<Value, RuntimeError> {
+        log::info!("RUN loop start");
+
+        loop {
+            let value = match self.block.run(source, context) {
+                Ok(value) => value,
+                Err(RuntimeError::Break { value }) => {
+                    log::info!("RUN loop end");
+
+                    return Ok(value);
+                }
+                Err(error) => return Err(error),
+            };
+
+            if self.block.contains_return() {
+                log::info!("RUN loop end");
+
+                return Ok(value);
+            }
+        }
+    }
+}
+
+impl Format for Loop {
+    fn format(&self, output: &mut String, indent_level: u8) {
+        output.push('\n');
+        Loop::indent(output, indent_level);
+        output.push_str("loop ");
+        self.block.format(output, indent_level);
+        output.push('\n');
+    }
+}
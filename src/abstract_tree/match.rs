@@ -7,7 +7,8 @@ use tree_sitter::Node as SyntaxNode;
 
 use crate::{
     error::{RuntimeError, SyntaxError, ValidationError},
-    AbstractTree, Context, Expression, Format, MatchPattern, Statement, Type, Value,
+    AbstractTree, Context, Expression, Format, MatchPattern, SourcePosition, Statement, Type,
+    Value, ValueNode,
 };
 
 /// Abstract representation of a match statement.
@@ -16,11 +17,39 @@ pub struct Match {
     matcher: Expression,
     options: Vec<(MatchPattern, Statement)>,
     fallback: Option<Box<Statement>>,
+    syntax_position: SourcePosition,
 
     #[serde(skip)]
     context: Context,
 }
 
+impl Match {
+    /// Check that a match on a boolean has a pattern for both `true` and
+    /// `false` or a wildcard.
+    fn validate_boolean_exhaustiveness(&self) -> Result<(), ValidationError> {
+        let mut missing = vec!["true".to_string(), "false".to_string()];
+
+        for (pattern, _) in &self.options {
+            match pattern {
+                MatchPattern::Wildcard => return Ok(()),
+                MatchPattern::Value(ValueNode::Boolean(boolean)) => {
+                    missing.retain(|value| value != boolean)
+                }
+                _ => {}
+            }
+        }
+
+        if self.fallback.is_some() || missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError::NonExhaustiveMatch {
+                missing,
+                position: self.syntax_position,
+            })
+        }
+    }
+}
+
 impl AbstractTree for Match {
     fn from_syntax(node: SyntaxNode, source: &str, context: &Context) -> Result<Self, SyntaxError> {
         SyntaxError::expect_syntax_node("match", node)?;
@@ -56,6 +85,7 @@ impl AbstractTree for Match {
             matcher,
             options,
             fallback,
+            syntax_position: node.range().into(),
             context: Context::default(),
         })
     }
@@ -84,6 +114,10 @@ impl AbstractTree for Match {
             statement.validate(_source, _context)?;
         }
 
+        if self.matcher.expected_type(_context)? == Type::Boolean {
+            self.validate_boolean_exhaustiveness()?;
+        }
+
         Ok(())
     }
 
@@ -91,6 +125,10 @@ impl AbstractTree for Match {
         let matcher_value = self.matcher.run(source, context)?;
 
         for (pattern, statement) in &self.options {
+            if let MatchPattern::Wildcard = pattern {
+                return statement.run(source, context);
+            }
+
             if let (Value::Enum(enum_instance), MatchPattern::EnumPattern(enum_pattern)) =
                 (&matcher_value, pattern)
             {
@@ -131,12 +169,12 @@ impl Format for Match {
 
         for (expression, statement) in &self.options {
             expression.format(output, indent_level);
-            output.push_str(" => ");
+            output.push_str(" -> ");
             statement.format(output, indent_level);
         }
 
         if let Some(statement) = &self.fallback {
-            output.push_str("* => ");
+            output.push_str("* -> ");
             statement.format(output, indent_level);
         }
 
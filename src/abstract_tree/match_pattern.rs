@@ -40,7 +40,7 @@ impl AbstractTree for MatchPattern {
         match self {
             MatchPattern::EnumPattern(enum_pattern) => enum_pattern.expected_type(_context),
             MatchPattern::Value(value_node) => value_node.expected_type(_context),
-            MatchPattern::Wildcard => todo!(),
+            MatchPattern::Wildcard => Ok(Type::Any),
         }
     }
 
@@ -58,7 +58,11 @@ impl AbstractTree for MatchPattern {
 }
 
 impl Format for MatchPattern {
-    fn format(&self, _output: &mut String, _indent_level: u8) {
-        todo!()
+    fn format(&self, output: &mut String, indent_level: u8) {
+        match self {
+            MatchPattern::EnumPattern(enum_pattern) => enum_pattern.format(output, indent_level),
+            MatchPattern::Value(value_node) => value_node.format(output, indent_level),
+            MatchPattern::Wildcard => output.push('*'),
+        }
     }
 }
@@ -20,6 +20,7 @@ pub mod index_assignment;
 pub mod index_expression;
 pub mod logic;
 pub mod logic_operator;
+pub mod r#loop;
 pub mod map_node;
 pub mod r#match;
 pub mod match_pattern;
@@ -38,7 +39,7 @@ pub use {
     enum_pattern::*, expression::*, function_call::*, function_expression::*, function_node::*,
     identifier::*, if_else::*, index::*, index_assignment::IndexAssignment, index_expression::*,
     logic::*, logic_operator::*, map_node::*, match_pattern::*, math::*, math_operator::*, r#as::*,
-    r#for::*, r#match::*, r#type::*, r#while::*, statement::*, struct_definition::*,
+    r#for::*, r#loop::*, r#match::*, r#type::*, r#while::*, statement::*, struct_definition::*,
     type_definition::*, type_specification::*, value_node::*,
 };
 
@@ -3,13 +3,14 @@ use serde::{Deserialize, Serialize};
 use crate::{
     error::{RuntimeError, SyntaxError, ValidationError},
     AbstractTree, Assignment, Block, Context, Expression, For, Format, IfElse, IndexAssignment,
-    Match, SyntaxNode, Type, TypeDefinition, Value, While,
+    Loop, Match, SourcePosition, SyntaxNode, Type, TypeDefinition, Value, While,
 };
 
 /// Abstract representation of a statement.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Statement {
     is_return: bool,
+    is_break: bool,
     statement_kind: StatementKind,
 }
 
@@ -28,7 +29,15 @@ impl AbstractTree for Statement {
         SyntaxError::expect_syntax_node("statement", node)?;
 
         let first_child = node.child(0).unwrap();
-        let mut is_return = first_child.kind() == "return" || first_child.kind() == "break";
+        let is_break = first_child.kind() == "break";
+
+        if is_break && !is_inside_loop(node) {
+            return Err(SyntaxError::BreakOutsideLoop {
+                position: SourcePosition::from(first_child.range()),
+            });
+        }
+
+        let mut is_return = first_child.kind() == "return" || is_break;
         let child = if is_return {
             node.child(1).unwrap()
         } else {
@@ -45,6 +54,7 @@ impl AbstractTree for Statement {
 
         Ok(Statement {
             is_return,
+            is_break,
             statement_kind,
         })
     }
@@ -58,8 +68,30 @@ impl AbstractTree for Statement {
     }
 
     fn run(&self, _source: &str, _context: &Context) -> Result<Value, RuntimeError> {
-        self.statement_kind.run(_source, _context)
+        let value = self.statement_kind.run(_source, _context)?;
+
+        if self.is_break {
+            Err(RuntimeError::Break { value })
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+/// Walks up from a statement to check that a loop, and not a function body,
+/// is the nearest thing that can end it.
+fn is_inside_loop(node: SyntaxNode) -> bool {
+    let mut parent = node.parent();
+
+    while let Some(node) = parent {
+        match node.kind() {
+            "loop_node" | "while" | "for" => return true,
+            "function" => return false,
+            _ => parent = node.parent(),
+        }
     }
+
+    false
 }
 
 impl Format for Statement {
@@ -78,6 +110,7 @@ enum StatementKind {
     Block(Box<Block>),
     For(Box<For>),
     IndexAssignment(Box<IndexAssignment>),
+    Loop(Box<Loop>),
     TypeDefinition(TypeDefinition),
 }
 
@@ -109,6 +142,9 @@ impl AbstractTree for StatementKind {
             "index_assignment" => Ok(StatementKind::IndexAssignment(Box::new(
                 IndexAssignment::from_syntax(child, source, context)?,
             ))),
+            "loop_node" => Ok(StatementKind::Loop(Box::new(Loop::from_syntax(
+                child, source, context,
+            )?))),
             "match" => Ok(StatementKind::Match(Match::from_syntax(
                 child, source, context,
             )?)),
@@ -117,7 +153,7 @@ impl AbstractTree for StatementKind {
             )?)),
             _ => Err(SyntaxError::UnexpectedSyntaxNode {
                 expected:
-                    "assignment, index assignment, expression, type_definition, block, return, if...else, while, for or match".to_string(),
+                    "assignment, index assignment, expression, type_definition, block, return, if...else, loop, while, for or match".to_string(),
                 actual: child.kind().to_string(),
                 position: node.range().into(),
             }),
@@ -136,6 +172,7 @@ impl AbstractTree for StatementKind {
             StatementKind::IndexAssignment(index_assignment) => {
                 index_assignment.expected_type(_context)
             }
+            StatementKind::Loop(r#loop) => r#loop.expected_type(_context),
             StatementKind::TypeDefinition(type_definition) => {
                 type_definition.expected_type(_context)
             }
@@ -154,6 +191,7 @@ impl AbstractTree for StatementKind {
             StatementKind::IndexAssignment(index_assignment) => {
                 index_assignment.validate(_source, _context)
             }
+            StatementKind::Loop(r#loop) => r#loop.validate(_source, _context),
             StatementKind::TypeDefinition(type_definition) => {
                 type_definition.validate(_source, _context)
             }
@@ -172,6 +210,7 @@ impl AbstractTree for StatementKind {
             StatementKind::IndexAssignment(index_assignment) => {
                 index_assignment.run(_source, _context)
             }
+            StatementKind::Loop(r#loop) => r#loop.run(_source, _context),
             StatementKind::TypeDefinition(type_definition) => {
                 type_definition.run(_source, _context)
             }
@@ -194,6 +233,7 @@ impl Format for StatementKind {
             StatementKind::IndexAssignment(index_assignment) => {
                 index_assignment.format(output, indent_level)
             }
+            StatementKind::Loop(r#loop) => r#loop.format(output, indent_level),
             StatementKind::TypeDefinition(type_definition) => {
                 type_definition.format(output, indent_level)
             }
@@ -35,6 +35,10 @@ pub enum Type {
     Number,
     String,
     Range,
+
+    /// Zero or more trailing arguments of the inner type. This is only valid as the final
+    /// parameter type of a function.
+    Variadic(Box<Type>),
 }
 
 impl Type {
@@ -46,10 +50,18 @@ impl Type {
         BuiltInType::Option(inner_type).get().clone()
     }
 
+    pub fn result(ok_type: Type, error_type: Type) -> Self {
+        Type::custom(Identifier::new("Result"), vec![ok_type, error_type])
+    }
+
     pub fn list(item_type: Type) -> Self {
         Type::ListOf(Box::new(item_type))
     }
 
+    pub fn variadic(item_type: Type) -> Self {
+        Type::Variadic(Box::new(item_type))
+    }
+
     pub fn function(parameter_types: Vec<Type>, return_type: Type) -> Self {
         Type::Function {
             parameter_types,
@@ -82,7 +94,7 @@ impl Type {
             | (Type::Float, Type::Float)
             | (Type::Integer, Type::Integer)
             | (Type::List, Type::List)
-            | (Type::Map(None), Type::Map(None))
+            | (Type::Map(None), Type::Map(_))
             | (Type::Number, Type::Number)
             | (Type::Number, Type::Integer)
             | (Type::Number, Type::Float)
@@ -104,6 +116,9 @@ impl Type {
             (Type::ListOf(self_item_type), Type::ListOf(other_item_type)) => {
                 self_item_type.accepts(&other_item_type)
             }
+            (Type::Variadic(self_item_type), Type::Variadic(other_item_type)) => {
+                self_item_type.accepts(other_item_type)
+            }
             (Type::ListExact(self_types), Type::ListExact(other_types)) => {
                 for (left, right) in self_types.iter().zip(other_types.iter()) {
                     if !left.accepts(right) {
@@ -115,7 +130,7 @@ impl Type {
             }
             (Type::ListExact(exact_types), Type::ListOf(of_type))
             | (Type::ListOf(of_type), Type::ListExact(exact_types)) => {
-                exact_types.iter().all(|r#type| r#type == of_type.as_ref())
+                exact_types.iter().all(|r#type| of_type.accepts(r#type))
             }
             (
                 Type::Function {
@@ -127,17 +142,21 @@ impl Type {
                     return_type: other_return_type,
                 },
             ) => {
+                if self_parameter_types.len() != other_parameter_types.len() {
+                    return false;
+                }
+
                 let parameter_type_pairs = self_parameter_types
                     .iter()
                     .zip(other_parameter_types.iter());
 
                 for (self_parameter_type, other_parameter_type) in parameter_type_pairs {
-                    if self_parameter_type == other_parameter_type {
+                    if !self_parameter_type.accepts(other_parameter_type) {
                         return false;
                     }
                 }
 
-                self_return_type == other_return_type
+                self_return_type.accepts(other_return_type)
             }
             _ => false,
         }
@@ -154,6 +173,10 @@ impl Type {
     pub fn is_map(&self) -> bool {
         matches!(self, Type::Map(_))
     }
+
+    pub fn is_variadic(&self) -> bool {
+        matches!(self, Type::Variadic(_))
+    }
 }
 
 impl AbstractTree for Type {
@@ -323,6 +346,10 @@ impl Format for Type {
             Type::Number => output.push_str("num"),
             Type::String => output.push_str("str"),
             Type::Range => todo!(),
+            Type::Variadic(item_type) => {
+                item_type.format(output, indent_level);
+                output.push_str("...");
+            }
         }
     }
 }
@@ -389,6 +416,7 @@ impl Display for Type {
             Type::None => write!(f, "none"),
             Type::String => write!(f, "str"),
             Type::Range => todo!(),
+            Type::Variadic(item_type) => write!(f, "{item_type}..."),
         }
     }
 }
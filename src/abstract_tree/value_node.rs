@@ -1,4 +1,7 @@
-use std::{cmp::Ordering, ops::RangeInclusive};
+use std::{
+    cmp::Ordering,
+    ops::{Range, RangeInclusive},
+};
 
 use serde::{Deserialize, Serialize};
 use tree_sitter::Node as SyntaxNode;
@@ -6,7 +9,7 @@ use tree_sitter::Node as SyntaxNode;
 use crate::{
     error::{RuntimeError, SyntaxError, ValidationError},
     AbstractTree, Context, Expression, Format, Function, FunctionNode,
-    Identifier, List, Type, Value, TypeDefinition, MapNode,
+    Identifier, List, Type, Value, TypeDefinition, MapNode, SourcePosition,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -46,8 +49,31 @@ impl AbstractTree for ValueNode {
             "integer" => ValueNode::Integer(source[child.byte_range()].to_string()),
             "string" => {
                 let without_quotes = child.start_byte() + 1..child.end_byte() - 1;
+                let string_source = &source[without_quotes.clone()];
+
+                // The source is kept as written so that formatting does not
+                // expand escapes. Decoding it here reports malformed escapes
+                // before the program runs.
+                if let Err(escape_range) = unescape(string_source) {
+                    let start_byte = without_quotes.start + escape_range.start;
+                    let end_byte = without_quotes.start + escape_range.end;
+                    let (start_row, start_column) = point_at(source, start_byte);
+                    let (end_row, end_column) = point_at(source, end_byte);
+
+                    return Err(SyntaxError::InvalidEscapeSequence {
+                        sequence: source[start_byte..end_byte].to_string(),
+                        position: SourcePosition {
+                            start_byte,
+                            end_byte,
+                            start_row,
+                            start_column,
+                            end_row,
+                            end_column,
+                        },
+                    });
+                }
 
-                ValueNode::String(source[without_quotes].to_string())
+                ValueNode::String(string_source.to_string())
             }
             "list" => {
                 let mut expressions = Vec::new();
@@ -200,9 +226,21 @@ impl AbstractTree for ValueNode {
 
                 Value::Float(float)
             }
-            ValueNode::Function(function) => Value::Function(function.clone()),
+            ValueNode::Function(function) => {
+                if let Function::ContextDefined(function_node) = function {
+                    let closure = function_node.capture(context)?;
+
+                    Value::Function(Function::ContextDefined(closure))
+                } else {
+                    Value::Function(function.clone())
+                }
+            }
             ValueNode::Integer(value_source) => Value::Integer(value_source.parse().unwrap()),
-            ValueNode::String(value_source) => Value::string(value_source.clone()),
+            ValueNode::String(value_source) => {
+                let string = unescape(value_source).unwrap_or_else(|_| value_source.clone());
+
+                Value::string(string)
+            }
             ValueNode::List(expressions) => {
                 let mut values = Vec::with_capacity(expressions.len());
 
@@ -355,3 +393,78 @@ impl PartialOrd for ValueNode {
         Some(self.cmp(other))
     }
 }
+
+/// Replaces the escape sequences in a string literal's source with the
+/// characters they stand for. On failure, returns the byte range of the first
+/// malformed escape.
+fn unescape(source: &str) -> Result<String, Range<usize>> {
+    let mut string = String::with_capacity(source.len());
+    let mut position = 0;
+
+    while let Some(offset) = source[position..].find('\\') {
+        let start = position + offset;
+        let escape = &source[start + 1..];
+
+        string.push_str(&source[position..start]);
+
+        let (char, length) = match escape.chars().next() {
+            Some('\\') => ('\\', 1),
+            Some('"') => ('"', 1),
+            Some('\'') => ('\'', 1),
+            Some('`') => ('`', 1),
+            Some('n') => ('\n', 1),
+            Some('r') => ('\r', 1),
+            Some('t') => ('\t', 1),
+            Some('0') => ('\0', 1),
+            Some('x') => {
+                let code = escape
+                    .get(1..3)
+                    .filter(|digits| digits.chars().all(|char| char.is_ascii_hexdigit()))
+                    .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                    .filter(|code| code.is_ascii());
+
+                match code {
+                    Some(code) => (code as char, 3),
+                    None => return Err(start..start + 2),
+                }
+            }
+            Some('u') => {
+                let closing = escape.find('}');
+                let char = match closing {
+                    Some(closing) if escape[1..].starts_with('{') && (3..=8).contains(&closing) => {
+                        Some(&escape[2..closing])
+                            .filter(|digits| digits.chars().all(|char| char.is_ascii_hexdigit()))
+                            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                            .and_then(char::from_u32)
+                    }
+                    _ => None,
+                };
+
+                match (char, closing) {
+                    (Some(char), Some(closing)) => (char, closing + 1),
+                    _ => return Err(start..start + 2),
+                }
+            }
+            Some(other) => return Err(start..start + 1 + other.len_utf8()),
+            None => return Err(start..start + 1),
+        };
+
+        string.push(char);
+
+        position = start + 1 + length;
+    }
+
+    string.push_str(&source[position..]);
+
+    Ok(string)
+}
+
+/// Returns the row and column of a byte offset. Like every [SourcePosition],
+/// the row is one-based and the column is zero-based.
+fn point_at(source: &str, byte: usize) -> (usize, usize) {
+    let before = &source[..byte];
+    let row = before.matches('\n').count() + 1;
+    let column = byte - before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+
+    (row, column)
+}
@@ -42,7 +42,11 @@ impl AbstractTree for While {
         log::info!("RUN while loop start");
 
         while self.expression.run(source, context)?.as_boolean()? {
-            self.block.run(source, context)?;
+            match self.block.run(source, context) {
+                Ok(_) => {}
+                Err(RuntimeError::Break { .. }) => break,
+                Err(error) => return Err(error),
+            }
         }
 
         log::info!("RUN while loop end");
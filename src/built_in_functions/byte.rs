@@ -0,0 +1,92 @@
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, Type, Value};
+
+use super::Callable;
+
+pub fn byte_functions() -> impl Iterator<Item = ByteFunction> {
+    enum_iterator::all()
+}
+
+/// ASCII tools for bytes, which are integers from 0 to 255.
+///
+/// The predicates return false for integers that are not ASCII bytes.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ByteFunction {
+    IsAlpha,
+    IsAlphanumeric,
+    IsDigit,
+    IsWhitespace,
+    ToChar,
+}
+
+impl Callable for ByteFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            ByteFunction::IsAlpha => "is_alpha",
+            ByteFunction::IsAlphanumeric => "is_alphanumeric",
+            ByteFunction::IsDigit => "is_digit",
+            ByteFunction::IsWhitespace => "is_whitespace",
+            ByteFunction::ToChar => "to_char",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            ByteFunction::IsAlpha => "Check if a byte is an ASCII letter.",
+            ByteFunction::IsAlphanumeric => "Check if a byte is an ASCII letter or digit.",
+            ByteFunction::IsDigit => "Check if a byte is an ASCII digit.",
+            ByteFunction::IsWhitespace => "Check if a byte is ASCII whitespace.",
+            ByteFunction::ToChar => "Convert an ASCII byte to a character.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            ByteFunction::ToChar => Type::function(
+                vec![Type::Integer],
+                Type::result(Type::String, Type::String),
+            ),
+            ByteFunction::IsAlpha
+            | ByteFunction::IsAlphanumeric
+            | ByteFunction::IsDigit
+            | ByteFunction::IsWhitespace => Type::function(vec![Type::Integer], Type::Boolean),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+        let integer = arguments.first().unwrap().as_integer()?;
+        let ascii = u8::try_from(integer).ok().filter(u8::is_ascii);
+        let value = match self {
+            ByteFunction::IsAlpha => {
+                Value::Boolean(ascii.is_some_and(|byte| byte.is_ascii_alphabetic()))
+            }
+            ByteFunction::IsAlphanumeric => {
+                Value::Boolean(ascii.is_some_and(|byte| byte.is_ascii_alphanumeric()))
+            }
+            ByteFunction::IsDigit => {
+                Value::Boolean(ascii.is_some_and(|byte| byte.is_ascii_digit()))
+            }
+            ByteFunction::IsWhitespace => {
+                Value::Boolean(ascii.is_some_and(|byte| byte.is_ascii_whitespace()))
+            }
+            ByteFunction::ToChar => {
+                if let Some(byte) = ascii {
+                    Value::ok(Value::string((byte as char).to_string()))
+                } else {
+                    Value::error(Value::string(format!("{integer} is not an ASCII byte.")))
+                }
+            }
+        };
+
+        Ok(value)
+    }
+}
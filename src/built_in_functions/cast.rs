@@ -0,0 +1,113 @@
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, Type, Value};
+
+use super::Callable;
+
+pub fn cast_functions() -> impl Iterator<Item = CastFunction> {
+    enum_iterator::all()
+}
+
+/// Runtime conversions that return a `Result` instead of failing validation.
+///
+/// Unlike `as`, which is checked before the program runs, these functions
+/// accept any value and return `Result::Error` with a message when the
+/// conversion is impossible.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CastFunction {
+    Boolean,
+    Float,
+    Integer,
+    String,
+}
+
+impl CastFunction {
+    fn target_type(&self) -> Type {
+        match self {
+            CastFunction::Boolean => Type::Boolean,
+            CastFunction::Float => Type::Float,
+            CastFunction::Integer => Type::Integer,
+            CastFunction::String => Type::String,
+        }
+    }
+
+    fn convert(&self, value: &Value) -> Option<Value> {
+        let converted = match (self, value) {
+            (CastFunction::Boolean, Value::Boolean(boolean)) => Value::Boolean(*boolean),
+            (CastFunction::Boolean, Value::Integer(integer)) => Value::Boolean(*integer != 0),
+            (CastFunction::Boolean, Value::Float(float)) => Value::Boolean(*float != 0.0),
+            (CastFunction::Boolean, Value::String(string)) => {
+                Value::Boolean(string.trim().parse().ok()?)
+            }
+            (CastFunction::Float, Value::Boolean(boolean)) => {
+                Value::Float(if *boolean { 1.0 } else { 0.0 })
+            }
+            (CastFunction::Float, Value::Integer(integer)) => Value::Float(*integer as f64),
+            (CastFunction::Float, Value::Float(float)) => Value::Float(*float),
+            (CastFunction::Float, Value::String(string)) => {
+                Value::Float(string.trim().parse().ok()?)
+            }
+            (CastFunction::Integer, Value::Boolean(boolean)) => Value::Integer(*boolean as i64),
+            (CastFunction::Integer, Value::Integer(integer)) => Value::Integer(*integer),
+            (CastFunction::Integer, Value::Float(float)) => Value::Integer(*float as i64),
+            (CastFunction::Integer, Value::String(string)) => {
+                Value::Integer(string.trim().parse().ok()?)
+            }
+            (CastFunction::String, value) => Value::string(value.to_string()),
+            _ => return None,
+        };
+
+        Some(converted)
+    }
+}
+
+impl Callable for CastFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            CastFunction::Boolean => "bool",
+            CastFunction::Float => "float",
+            CastFunction::Integer => "int",
+            CastFunction::String => "str",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            CastFunction::Boolean => "Convert a value to a boolean, if possible.",
+            CastFunction::Float => "Convert a value to a float, if possible.",
+            CastFunction::Integer => "Convert a value to an integer, if possible.",
+            CastFunction::String => "Convert a value to a string.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        Type::function(
+            vec![Type::Any],
+            Type::result(self.target_type(), Type::String),
+        )
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+        let value = arguments.first().unwrap();
+
+        if let Some(converted) = self.convert(value) {
+            Ok(Value::ok(converted))
+        } else {
+            let message = format!(
+                "Cannot convert {} to {}.",
+                value.r#type()?,
+                self.target_type()
+            );
+
+            Ok(Value::error(Value::string(message)))
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::{
+    mpsc::{channel, Receiver, Sender},
+    Arc, Mutex, OnceLock,
+};
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, Identifier, List, Type, Value};
+
+use super::{handle, handle_id, Callable};
+
+static CHANNELS: OnceLock<Mutex<Vec<Channel>>> = OnceLock::new();
+
+struct Channel {
+    sender: Option<Sender<Value>>,
+    receiver: Arc<Mutex<Receiver<Value>>>,
+}
+
+fn channels() -> &'static Mutex<Vec<Channel>> {
+    CHANNELS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+pub fn channel_functions() -> impl Iterator<Item = ChannelFunction> {
+    enum_iterator::all()
+}
+
+/// Unbounded channels for passing values between `async` blocks.
+///
+/// The handles returned by `channel:new` are `Sender` and `Receiver` struct
+/// instances that refer to a channel by id. Because handles are plain values
+/// and are never dropped, the sending side stays open until `channel:close`.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChannelFunction {
+    Close,
+    New,
+    Recv,
+    Send,
+    TryRecv,
+}
+
+impl Callable for ChannelFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            ChannelFunction::Close => "close",
+            ChannelFunction::New => "new",
+            ChannelFunction::Recv => "recv",
+            ChannelFunction::Send => "send",
+            ChannelFunction::TryRecv => "try_recv",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            ChannelFunction::Close => "Close the sending side of a channel.",
+            ChannelFunction::New => "Create a channel and return its sender and receiver.",
+            ChannelFunction::Recv => {
+                "Wait for the next value. Returns none if the channel is closed and empty."
+            }
+            ChannelFunction::Send => {
+                "Send a value through a channel. Returns false if the channel is closed."
+            }
+            ChannelFunction::TryRecv => "Get the next value without waiting, if there is one.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        let sender = Type::custom(Identifier::new("Sender"), Vec::with_capacity(0));
+        let receiver = Type::custom(Identifier::new("Receiver"), Vec::with_capacity(0));
+
+        match self {
+            ChannelFunction::Close => Type::function(vec![sender], Type::None),
+            ChannelFunction::New => Type::function(vec![], Type::ListExact(vec![sender, receiver])),
+            ChannelFunction::Recv | ChannelFunction::TryRecv => {
+                Type::function(vec![receiver], Type::option(Some(Type::Any)))
+            }
+            ChannelFunction::Send => Type::function(vec![sender, Type::Any], Type::Boolean),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        let value = match self {
+            ChannelFunction::Close => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let id = handle_id(arguments.first().unwrap(), "Sender")?;
+
+                if let Some(channel) = channels().lock()?.get_mut(id) {
+                    channel.sender = None;
+                }
+
+                Value::none()
+            }
+            ChannelFunction::New => {
+                RuntimeError::expect_argument_amount(self.name(), 0, arguments.len())?;
+
+                let (sender, receiver) = channel();
+                let mut channels = channels().lock()?;
+                let id = channels.len();
+
+                channels.push(Channel {
+                    sender: Some(sender),
+                    receiver: Arc::new(Mutex::new(receiver)),
+                });
+
+                Value::List(List::with_items(vec![
+                    handle("Sender", id),
+                    handle("Receiver", id),
+                ]))
+            }
+            ChannelFunction::Recv | ChannelFunction::TryRecv => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let id = handle_id(arguments.first().unwrap(), "Receiver")?;
+                let receiver = match channels().lock()?.get(id) {
+                    Some(channel) => channel.receiver.clone(),
+                    None => return Ok(Value::none()),
+                };
+                let receiver = receiver.lock()?;
+                let received = if let ChannelFunction::Recv = self {
+                    receiver.recv().ok()
+                } else {
+                    receiver.try_recv().ok()
+                };
+
+                match received {
+                    Some(value) => Value::some(value),
+                    None => Value::none(),
+                }
+            }
+            ChannelFunction::Send => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let id = handle_id(arguments.first().unwrap(), "Sender")?;
+                let sender = channels()
+                    .lock()?
+                    .get(id)
+                    .and_then(|channel| channel.sender.clone());
+                let sent = match sender {
+                    Some(sender) => sender.send(arguments.get(1).unwrap().clone()).is_ok(),
+                    None => false,
+                };
+
+                Value::Boolean(sent)
+            }
+        };
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,64 @@
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, Type, Value};
+
+use super::Callable;
+
+pub fn char_functions() -> impl Iterator<Item = CharFunction> {
+    enum_iterator::all()
+}
+
+/// Tools for characters, which are strings of exactly one character.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CharFunction {
+    ToByte,
+}
+
+impl Callable for CharFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            CharFunction::ToByte => "to_byte",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            CharFunction::ToByte => "Convert an ASCII character to its byte value.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            CharFunction::ToByte => Type::function(
+                vec![Type::String],
+                Type::result(Type::Integer, Type::String),
+            ),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        let value = match self {
+            CharFunction::ToByte => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let char = arguments.first().unwrap().as_char()?;
+
+                if char.is_ascii() {
+                    Value::ok(Value::Integer(char as i64))
+                } else {
+                    Value::error(Value::string(format!(
+                        "'{char}' is not an ASCII character."
+                    )))
+                }
+            }
+        };
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,117 @@
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+use crate::{error::RuntimeError, Context, Type, Value};
+
+use super::Callable;
+
+pub fn date_functions() -> impl Iterator<Item = DateFunction> {
+    enum_iterator::all()
+}
+
+/// Date tools that represent points in time as Unix timestamps in seconds.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DateFunction {
+    Format,
+    NowUtc,
+    Parse,
+}
+
+impl Callable for DateFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            DateFunction::Format => "format",
+            DateFunction::NowUtc => "now_utc",
+            DateFunction::Parse => "parse",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            DateFunction::Format => "Format a Unix timestamp with %Y, %m, %d, %H, %M and %S.",
+            DateFunction::NowUtc => "Get the current time as a Unix timestamp.",
+            DateFunction::Parse => "Parse an ISO 8601 date-time into a Unix timestamp.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            DateFunction::Format => Type::function(vec![Type::Integer, Type::String], Type::String),
+            DateFunction::NowUtc => Type::function(vec![], Type::Integer),
+            DateFunction::Parse => Type::function(
+                vec![Type::String],
+                Type::result(Type::Integer, Type::String),
+            ),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        let value = match self {
+            DateFunction::Format => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let timestamp = arguments.first().unwrap().as_integer()?;
+                let format = arguments.get(1).unwrap().as_string()?;
+                let date_time = OffsetDateTime::from_unix_timestamp(timestamp)?;
+
+                Value::String(format_date_time(date_time, format)?)
+            }
+            DateFunction::NowUtc => {
+                RuntimeError::expect_argument_amount(self.name(), 0, arguments.len())?;
+
+                Value::Integer(OffsetDateTime::now_utc().unix_timestamp())
+            }
+            DateFunction::Parse => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                match OffsetDateTime::parse(string, &Iso8601::DEFAULT) {
+                    Ok(date_time) => Value::ok(Value::Integer(date_time.unix_timestamp())),
+                    Err(error) => Value::error(Value::string(error.to_string())),
+                }
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+fn format_date_time(date_time: OffsetDateTime, format: &str) -> Result<String, RuntimeError> {
+    let mut output = String::with_capacity(format.len());
+    let mut chars = format.chars();
+
+    while let Some(char) = chars.next() {
+        if char != '%' {
+            output.push(char);
+
+            continue;
+        }
+
+        let specifier = chars.next();
+        let field = match specifier {
+            Some('Y') => format!("{:04}", date_time.year()),
+            Some('m') => format!("{:02}", date_time.month() as u8),
+            Some('d') => format!("{:02}", date_time.day()),
+            Some('H') => format!("{:02}", date_time.hour()),
+            Some('M') => format!("{:02}", date_time.minute()),
+            Some('S') => format!("{:02}", date_time.second()),
+            Some('%') => "%".to_string(),
+            _ => {
+                return Err(RuntimeError::InvalidFormatSpecifier {
+                    specifier: format!("%{}", specifier.map(String::from).unwrap_or_default()),
+                })
+            }
+        };
+
+        output.push_str(&field);
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,112 @@
+use std::fs::{create_dir_all, read_dir, remove_dir, remove_dir_all};
+
+use enum_iterator::{all, Sequence};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, List, Type, Value};
+
+use super::Callable;
+
+pub fn dir_functions() -> impl Iterator<Item = Dir> {
+    all()
+}
+
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dir {
+    Create,
+    List,
+    Remove,
+}
+
+impl Callable for Dir {
+    fn name(&self) -> &'static str {
+        match self {
+            Dir::Create => "create",
+            Dir::List => "list",
+            Dir::Remove => "remove",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Dir::Create => "Create a directory and any missing parent directories.",
+            Dir::List => "List the names of the entries in a directory.",
+            Dir::Remove => "Remove a directory, optionally removing its contents.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            Dir::Create => Type::function(
+                vec![Type::String],
+                Type::result(Type::Boolean, Type::String),
+            ),
+            Dir::List => Type::function(
+                vec![Type::String],
+                Type::result(Type::list(Type::String), Type::String),
+            ),
+            Dir::Remove => Type::function(
+                vec![Type::String, Type::Boolean],
+                Type::result(Type::Boolean, Type::String),
+            ),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _outer_context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        match self {
+            Dir::Create => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let path = arguments.first().unwrap().as_string()?;
+
+                match create_dir_all(path) {
+                    Ok(()) => Ok(Value::ok(Value::Boolean(true))),
+                    Err(error) => Ok(Value::error(Value::string(error.to_string()))),
+                }
+            }
+            Dir::List => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let path = arguments.first().unwrap().as_string()?;
+                let entries = match read_dir(path) {
+                    Ok(entries) => entries,
+                    Err(error) => return Ok(Value::error(Value::string(error.to_string()))),
+                };
+                let mut names = Vec::new();
+
+                for entry in entries {
+                    let name = entry?.file_name().to_string_lossy().to_string();
+
+                    names.push(name);
+                }
+
+                names.sort();
+
+                let items = names.into_iter().map(Value::string).collect();
+
+                Ok(Value::ok(Value::List(List::with_items(items))))
+            }
+            Dir::Remove => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let path = arguments.first().unwrap().as_string()?;
+                let recursive = arguments.get(1).unwrap().as_boolean()?;
+                let result = if recursive {
+                    remove_dir_all(path)
+                } else {
+                    remove_dir(path)
+                };
+
+                match result {
+                    Ok(()) => Ok(Value::ok(Value::Boolean(true))),
+                    Err(error) => Ok(Value::error(Value::string(error.to_string()))),
+                }
+            }
+        }
+    }
+}
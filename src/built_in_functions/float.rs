@@ -0,0 +1,229 @@
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, Type, Value};
+
+use super::Callable;
+
+pub fn float_functions() -> impl Iterator<Item = FloatFunction> {
+    enum_iterator::all()
+}
+
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FloatFunction {
+    Abs,
+    Acos,
+    Asin,
+    Atan,
+    Atan2,
+    Cbrt,
+    Ceil,
+    Clamp,
+    Cos,
+    Exp,
+    Floor,
+    Hypot,
+    Ln,
+    Log10,
+    Log2,
+    Max,
+    Min,
+    Pow,
+    Round,
+    Sign,
+    Sin,
+    Sqrt,
+    Tan,
+    Trunc,
+}
+
+impl Callable for FloatFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            FloatFunction::Abs => "abs",
+            FloatFunction::Acos => "acos",
+            FloatFunction::Asin => "asin",
+            FloatFunction::Atan => "atan",
+            FloatFunction::Atan2 => "atan2",
+            FloatFunction::Cbrt => "cbrt",
+            FloatFunction::Ceil => "ceil",
+            FloatFunction::Clamp => "clamp",
+            FloatFunction::Cos => "cos",
+            FloatFunction::Exp => "exp",
+            FloatFunction::Floor => "floor",
+            FloatFunction::Hypot => "hypot",
+            FloatFunction::Ln => "ln",
+            FloatFunction::Log10 => "log10",
+            FloatFunction::Log2 => "log2",
+            FloatFunction::Max => "max",
+            FloatFunction::Min => "min",
+            FloatFunction::Pow => "pow",
+            FloatFunction::Round => "round",
+            FloatFunction::Sign => "sign",
+            FloatFunction::Sin => "sin",
+            FloatFunction::Sqrt => "sqrt",
+            FloatFunction::Tan => "tan",
+            FloatFunction::Trunc => "trunc",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            FloatFunction::Abs => "Get the absolute value of a float.",
+            FloatFunction::Acos => "Get the arccosine of a float, in radians.",
+            FloatFunction::Asin => "Get the arcsine of a float, in radians.",
+            FloatFunction::Atan => "Get the arctangent of a float, in radians.",
+            FloatFunction::Atan2 => {
+                "Get the angle of the point (x, y) from the first argument y and the second x."
+            }
+            FloatFunction::Cbrt => "Get the cube root of a float.",
+            FloatFunction::Ceil => "Round a float up to the nearest whole number.",
+            FloatFunction::Clamp => "Restrict a float to a range from a minimum to a maximum.",
+            FloatFunction::Cos => "Get the cosine of an angle in radians.",
+            FloatFunction::Exp => "Raise e to a float power.",
+            FloatFunction::Floor => "Round a float down to the nearest whole number.",
+            FloatFunction::Hypot => {
+                "Get the length of the hypotenuse of a right triangle from its two sides."
+            }
+            FloatFunction::Ln => "Get the natural logarithm of a float.",
+            FloatFunction::Log10 => "Get the base 10 logarithm of a float.",
+            FloatFunction::Log2 => "Get the base 2 logarithm of a float.",
+            FloatFunction::Max => "Get the greater of two floats, ignoring NaN.",
+            FloatFunction::Min => "Get the lesser of two floats, ignoring NaN.",
+            FloatFunction::Pow => "Raise a float to a float power.",
+            FloatFunction::Round => {
+                "Round a float to the nearest whole number, away from zero at halves."
+            }
+            FloatFunction::Sign => {
+                "Get 1.0 or -1.0 for the sign of a float, or the float itself if it is zero or NaN."
+            }
+            FloatFunction::Sin => "Get the sine of an angle in radians.",
+            FloatFunction::Sqrt => "Get the square root of a float.",
+            FloatFunction::Tan => "Get the tangent of an angle in radians.",
+            FloatFunction::Trunc => "Remove the fractional part of a float.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            FloatFunction::Abs
+            | FloatFunction::Acos
+            | FloatFunction::Asin
+            | FloatFunction::Atan
+            | FloatFunction::Cbrt
+            | FloatFunction::Ceil
+            | FloatFunction::Cos
+            | FloatFunction::Exp
+            | FloatFunction::Floor
+            | FloatFunction::Ln
+            | FloatFunction::Log10
+            | FloatFunction::Log2
+            | FloatFunction::Round
+            | FloatFunction::Sign
+            | FloatFunction::Sin
+            | FloatFunction::Sqrt
+            | FloatFunction::Tan
+            | FloatFunction::Trunc => Type::function(vec![Type::Float], Type::Float),
+            FloatFunction::Atan2
+            | FloatFunction::Hypot
+            | FloatFunction::Max
+            | FloatFunction::Min
+            | FloatFunction::Pow => Type::function(vec![Type::Float, Type::Float], Type::Float),
+            FloatFunction::Clamp => {
+                Type::function(vec![Type::Float, Type::Float, Type::Float], Type::Float)
+            }
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        let value = match self {
+            FloatFunction::Abs
+            | FloatFunction::Acos
+            | FloatFunction::Asin
+            | FloatFunction::Atan
+            | FloatFunction::Cbrt
+            | FloatFunction::Ceil
+            | FloatFunction::Cos
+            | FloatFunction::Exp
+            | FloatFunction::Floor
+            | FloatFunction::Ln
+            | FloatFunction::Log10
+            | FloatFunction::Log2
+            | FloatFunction::Round
+            | FloatFunction::Sign
+            | FloatFunction::Sin
+            | FloatFunction::Sqrt
+            | FloatFunction::Tan
+            | FloatFunction::Trunc => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let float = arguments.first().unwrap().as_float()?;
+                let result = match self {
+                    FloatFunction::Abs => float.abs(),
+                    FloatFunction::Acos => float.acos(),
+                    FloatFunction::Asin => float.asin(),
+                    FloatFunction::Atan => float.atan(),
+                    FloatFunction::Cbrt => float.cbrt(),
+                    FloatFunction::Ceil => float.ceil(),
+                    FloatFunction::Cos => float.cos(),
+                    FloatFunction::Exp => float.exp(),
+                    FloatFunction::Floor => float.floor(),
+                    FloatFunction::Ln => float.ln(),
+                    FloatFunction::Log10 => float.log10(),
+                    FloatFunction::Log2 => float.log2(),
+                    FloatFunction::Round => float.round(),
+                    FloatFunction::Sin => float.sin(),
+                    FloatFunction::Sqrt => float.sqrt(),
+                    FloatFunction::Tan => float.tan(),
+                    FloatFunction::Trunc => float.trunc(),
+                    _ => {
+                        if float == 0.0 || float.is_nan() {
+                            float
+                        } else {
+                            float.signum()
+                        }
+                    }
+                };
+
+                Value::Float(result)
+            }
+            FloatFunction::Atan2
+            | FloatFunction::Hypot
+            | FloatFunction::Max
+            | FloatFunction::Min
+            | FloatFunction::Pow => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let left = arguments.first().unwrap().as_float()?;
+                let right = arguments.get(1).unwrap().as_float()?;
+                let result = match self {
+                    FloatFunction::Atan2 => left.atan2(right),
+                    FloatFunction::Hypot => left.hypot(right),
+                    FloatFunction::Max => left.max(right),
+                    FloatFunction::Min => left.min(right),
+                    _ => left.powf(right),
+                };
+
+                Value::Float(result)
+            }
+            FloatFunction::Clamp => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let float = arguments.first().unwrap().as_float()?;
+                let min = arguments.get(1).unwrap().as_float()?;
+                let max = arguments.get(2).unwrap().as_float()?;
+
+                // Unlike f64::clamp, this does not panic if min is greater
+                // than max. The maximum wins instead.
+                Value::Float(float.max(min).min(max))
+            }
+        };
+
+        Ok(value)
+    }
+}
@@ -1,4 +1,8 @@
-use std::{fs::File, io::Read};
+use std::{
+    fs::{remove_file, File, OpenOptions},
+    io::{Read, Write},
+    path::{Component, Path},
+};
 
 use enum_iterator::{all, Sequence};
 use serde::{Deserialize, Serialize};
@@ -13,25 +17,46 @@ pub fn fs_functions() -> impl Iterator<Item = Fs> {
 
 #[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Fs {
+    AppendFile,
+    Exists,
     ReadFile,
+    RemoveFile,
+    WriteFile,
 }
 
 impl Callable for Fs {
     fn name(&self) -> &'static str {
         match self {
+            Fs::AppendFile => "append_file",
+            Fs::Exists => "exists",
             Fs::ReadFile => "read_file",
+            Fs::RemoveFile => "remove_file",
+            Fs::WriteFile => "write_file",
         }
     }
 
     fn description(&self) -> &'static str {
         match self {
+            Fs::AppendFile => "Add a string to the end of a file, creating the file if needed.",
+            Fs::Exists => "Check if a file or directory exists.",
             Fs::ReadFile => "Read the contents of a file to a string.",
+            Fs::RemoveFile => "Remove a file.",
+            Fs::WriteFile => "Replace the contents of a file, creating the file if needed.",
         }
     }
 
     fn r#type(&self) -> Type {
         match self {
+            Fs::AppendFile | Fs::WriteFile => Type::function(
+                vec![Type::String, Type::String],
+                Type::result(Type::Boolean, Type::String),
+            ),
+            Fs::Exists => Type::function(vec![Type::String], Type::Boolean),
             Fs::ReadFile => Type::function(vec![Type::String], Type::String),
+            Fs::RemoveFile => Type::function(
+                vec![Type::String],
+                Type::result(Type::Boolean, Type::String),
+            ),
         }
     }
 
@@ -39,13 +64,46 @@ impl Callable for Fs {
         &self,
         arguments: &[Value],
         _source: &str,
-        _outer_context: &Context,
+        outer_context: &Context,
     ) -> Result<Value, RuntimeError> {
         match self {
+            Fs::AppendFile | Fs::WriteFile => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let path = arguments.first().unwrap().as_string()?;
+
+                self.expect_io_allowed(path, outer_context)?;
+
+                let content = arguments.get(1).unwrap().as_string()?;
+                let file = OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .append(*self == Fs::AppendFile)
+                    .truncate(*self == Fs::WriteFile)
+                    .open(path);
+                let written = file.and_then(|mut file| file.write_all(content.as_bytes()));
+
+                match written {
+                    Ok(()) => Ok(Value::ok(Value::Boolean(true))),
+                    Err(error) => Ok(Value::error(Value::string(error.to_string()))),
+                }
+            }
+            Fs::Exists => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let path = arguments.first().unwrap().as_string()?;
+
+                self.expect_inside_sandbox(path, outer_context)?;
+
+                Ok(Value::Boolean(Path::new(path).exists()))
+            }
             Fs::ReadFile => {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
 
                 let path = arguments.first().unwrap().as_string()?;
+
+                self.expect_inside_sandbox(path, outer_context)?;
+
                 let mut file = File::open(path)?;
                 let file_size = file.metadata()?.len() as usize;
                 let mut file_content = String::with_capacity(file_size);
@@ -54,6 +112,52 @@ impl Callable for Fs {
 
                 Ok(Value::string(file_content))
             }
+            Fs::RemoveFile => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let path = arguments.first().unwrap().as_string()?;
+
+                self.expect_io_allowed(path, outer_context)?;
+
+                match remove_file(path) {
+                    Ok(()) => Ok(Value::ok(Value::Boolean(true))),
+                    Err(error) => Ok(Value::error(Value::string(error.to_string()))),
+                }
+            }
+        }
+    }
+}
+
+impl Fs {
+    /// Fails unless the context allows IO. Used by functions that change
+    /// files, which a sandboxed program may not do at all.
+    fn expect_io_allowed(&self, path: &str, context: &Context) -> Result<(), RuntimeError> {
+        if context.allows_io() {
+            Ok(())
+        } else {
+            Err(self.io_not_allowed(path))
+        }
+    }
+
+    /// Fails if the context is sandboxed and the path could lead outside of
+    /// the working directory, either because it is absolute or because it
+    /// contains `..`.
+    fn expect_inside_sandbox(&self, path: &str, context: &Context) -> Result<(), RuntimeError> {
+        let is_inside = Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+        if is_inside || context.allows_io() {
+            Ok(())
+        } else {
+            Err(self.io_not_allowed(path))
+        }
+    }
+
+    fn io_not_allowed(&self, path: &str) -> RuntimeError {
+        RuntimeError::IoNotAllowed {
+            function_name: format!("fs:{}", self.name()),
+            path: path.to_string(),
         }
     }
 }
@@ -0,0 +1,97 @@
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh64::xxh64;
+
+use crate::{error::RuntimeError, Context, Type, Value};
+
+use super::Callable;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+pub fn hash_functions() -> impl Iterator<Item = HashFunction> {
+    enum_iterator::all()
+}
+
+/// Non-cryptographic hash functions.
+///
+/// Hashes are returned as the signed reinterpretation of the 64-bit hash, so
+/// they are stable between runs and fit in a dust integer.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HashFunction {
+    Fnv1a,
+    Fnv1aBytes,
+    XxHash,
+    XxHashBytes,
+}
+
+impl Callable for HashFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            HashFunction::Fnv1a => "fnv1a",
+            HashFunction::Fnv1aBytes => "fnv1a_bytes",
+            HashFunction::XxHash => "xxhash",
+            HashFunction::XxHashBytes => "xxhash_bytes",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            HashFunction::Fnv1a => "Hash a string with 64-bit FNV-1a.",
+            HashFunction::Fnv1aBytes => "Hash a list of bytes with 64-bit FNV-1a.",
+            HashFunction::XxHash => "Hash a string with xxHash64.",
+            HashFunction::XxHashBytes => "Hash a list of bytes with xxHash64.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            HashFunction::Fnv1a | HashFunction::XxHash => {
+                Type::function(vec![Type::String], Type::Integer)
+            }
+            HashFunction::Fnv1aBytes | HashFunction::XxHashBytes => {
+                Type::function(vec![Type::list(Type::Integer)], Type::Integer)
+            }
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+        let argument = arguments.first().unwrap();
+        let bytes = match self {
+            HashFunction::Fnv1a | HashFunction::XxHash => argument.as_string()?.as_bytes().to_vec(),
+            HashFunction::Fnv1aBytes | HashFunction::XxHashBytes => {
+                let mut bytes = Vec::new();
+
+                for item in argument.as_list()?.items()?.iter() {
+                    bytes.push(item.as_integer()? as u8);
+                }
+
+                bytes
+            }
+        };
+        let hash = match self {
+            HashFunction::Fnv1a | HashFunction::Fnv1aBytes => fnv1a(&bytes),
+            HashFunction::XxHash | HashFunction::XxHashBytes => xxh64(&bytes, 0),
+        };
+
+        Ok(Value::Integer(hash as i64))
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    hash
+}
@@ -0,0 +1,667 @@
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, List, Type, Value};
+
+use super::Callable;
+
+pub fn int_functions() -> impl Iterator<Item = IntFunction> {
+    enum_iterator::all()
+}
+
+/// Integer arithmetic with explicit overflow behavior.
+///
+/// The `+` and `-` operators saturate at the integer bounds. These functions
+/// let a program choose wrapping or checked arithmetic instead.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntFunction {
+    Abs,
+    BitAnd,
+    BitNot,
+    BitOr,
+    BitRange,
+    BitXor,
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    CheckedRem,
+    CheckedSub,
+    DivRem,
+    Format,
+    FullMul,
+    Gcd,
+    GetBit,
+    IsPrime,
+    Isqrt,
+    Lcm,
+    MulHigh,
+    Pow,
+    Sar,
+    SetBit,
+    Shl,
+    Shr,
+    ToBinary,
+    ToHex,
+    ToHexUpper,
+    ToOctal,
+    ToggleBit,
+    WrappingAdd,
+    WrappingMul,
+    WrappingSub,
+}
+
+impl IntFunction {
+    /// Returns the integer argument and a bit position checked to be from 0
+    /// to 63.
+    fn integer_and_bit(&self, arguments: &[Value]) -> Result<(i64, u32), RuntimeError> {
+        let integer = arguments.first().unwrap().as_integer()?;
+        let position = arguments.get(1).unwrap().as_integer()?;
+
+        Ok((integer, bit_position(position, 63)?))
+    }
+
+    fn integer_pair(&self, arguments: &[Value]) -> Result<(i64, i64), RuntimeError> {
+        RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+        let left = arguments.first().unwrap().as_integer()?;
+        let right = arguments.get(1).unwrap().as_integer()?;
+
+        Ok((left, right))
+    }
+}
+
+impl Callable for IntFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            IntFunction::Abs => "abs",
+            IntFunction::BitAnd => "bit_and",
+            IntFunction::BitNot => "bit_not",
+            IntFunction::BitOr => "bit_or",
+            IntFunction::BitRange => "bit_range",
+            IntFunction::BitXor => "bit_xor",
+            IntFunction::CheckedAdd => "checked_add",
+            IntFunction::CheckedDiv => "checked_div",
+            IntFunction::CheckedMul => "checked_mul",
+            IntFunction::CheckedRem => "checked_rem",
+            IntFunction::CheckedSub => "checked_sub",
+            IntFunction::DivRem => "div_rem",
+            IntFunction::Format => "format",
+            IntFunction::FullMul => "full_mul",
+            IntFunction::Gcd => "gcd",
+            IntFunction::GetBit => "get_bit",
+            IntFunction::IsPrime => "is_prime",
+            IntFunction::Isqrt => "isqrt",
+            IntFunction::Lcm => "lcm",
+            IntFunction::MulHigh => "mul_high",
+            IntFunction::Pow => "pow",
+            IntFunction::Sar => "sar",
+            IntFunction::SetBit => "set_bit",
+            IntFunction::Shl => "shl",
+            IntFunction::Shr => "shr",
+            IntFunction::ToBinary => "to_binary",
+            IntFunction::ToHex => "to_hex",
+            IntFunction::ToHexUpper => "to_hex_upper",
+            IntFunction::ToOctal => "to_octal",
+            IntFunction::ToggleBit => "toggle_bit",
+            IntFunction::WrappingAdd => "wrapping_add",
+            IntFunction::WrappingMul => "wrapping_mul",
+            IntFunction::WrappingSub => "wrapping_sub",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            IntFunction::Abs => "Get the absolute value, or an error if it would overflow.",
+            IntFunction::BitAnd => "Get the bitwise AND of two integers.",
+            IntFunction::BitNot => "Flip every bit of an integer.",
+            IntFunction::BitOr => "Get the bitwise OR of two integers.",
+            IntFunction::BitRange => "Get the bits from a low position up to a high position.",
+            IntFunction::BitXor => "Get the bitwise exclusive OR of two integers.",
+            IntFunction::CheckedAdd => "Add two integers, or return an error on overflow.",
+            IntFunction::CheckedDiv => {
+                "Divide two integers, or return an error on overflow or division by zero."
+            }
+            IntFunction::CheckedMul => "Multiply two integers, or return an error on overflow.",
+            IntFunction::CheckedRem => {
+                "Get the remainder, or return an error on overflow or division by zero."
+            }
+            IntFunction::CheckedSub => "Subtract two integers, or return an error on overflow.",
+            IntFunction::DivRem => {
+                "Get the quotient and remainder, or an error when dividing by zero."
+            }
+            IntFunction::Format => "Format an integer in a radix, padded to a minimum width.",
+            IntFunction::FullMul => "Multiply into 128 bits and get the high and low halves.",
+            IntFunction::Gcd => "Get the non-negative greatest common divisor of two integers.",
+            IntFunction::GetBit => "Check if the bit at a position is set, counting from 0.",
+            IntFunction::IsPrime => "Check if an integer is a prime number.",
+            IntFunction::Isqrt => {
+                "Get the integer square root, or an error for a negative integer."
+            }
+            IntFunction::Lcm => "Get the least common multiple, or an error on overflow.",
+            IntFunction::MulHigh => "Multiply into 128 bits and get the high half.",
+            IntFunction::Pow => "Raise to a non-negative power, or return an error on overflow.",
+            IntFunction::Sar => {
+                "Shift right, keeping the sign, or return an error for a bad amount."
+            }
+            IntFunction::SetBit => "Set or clear the bit at a position.",
+            IntFunction::Shl => "Shift left, or return an error for an amount outside 0 to 63.",
+            IntFunction::Shr => {
+                "Shift right, filling with zeros, or return an error for a bad amount."
+            }
+            IntFunction::ToBinary => "Format an integer in binary.",
+            IntFunction::ToHex => "Format an integer in lowercase hexadecimal.",
+            IntFunction::ToHexUpper => "Format an integer in uppercase hexadecimal.",
+            IntFunction::ToOctal => "Format an integer in octal.",
+            IntFunction::ToggleBit => "Flip the bit at a position.",
+            IntFunction::WrappingAdd => "Add two integers, wrapping around on overflow.",
+            IntFunction::WrappingMul => "Multiply two integers, wrapping around on overflow.",
+            IntFunction::WrappingSub => "Subtract two integers, wrapping around on overflow.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            IntFunction::Abs => Type::function(
+                vec![Type::Integer],
+                Type::result(Type::Integer, Type::String),
+            ),
+            IntFunction::BitAnd | IntFunction::BitOr | IntFunction::BitXor => {
+                Type::function(vec![Type::Integer, Type::Integer], Type::Integer)
+            }
+            IntFunction::BitNot => Type::function(vec![Type::Integer], Type::Integer),
+            IntFunction::BitRange => Type::function(
+                vec![Type::Integer, Type::Integer, Type::Integer],
+                Type::Integer,
+            ),
+            IntFunction::CheckedAdd
+            | IntFunction::CheckedDiv
+            | IntFunction::CheckedMul
+            | IntFunction::CheckedRem
+            | IntFunction::CheckedSub
+            | IntFunction::Pow
+            | IntFunction::Sar
+            | IntFunction::Shl
+            | IntFunction::Shr => Type::function(
+                vec![Type::Integer, Type::Integer],
+                Type::result(Type::Integer, Type::String),
+            ),
+            IntFunction::DivRem => Type::function(
+                vec![Type::Integer, Type::Integer],
+                Type::result(Type::list(Type::Integer), Type::String),
+            ),
+            IntFunction::Format => Type::function(
+                vec![Type::Integer, Type::Integer, Type::Integer, Type::String],
+                Type::String,
+            ),
+            IntFunction::FullMul => Type::function(
+                vec![Type::Integer, Type::Integer],
+                Type::list(Type::Integer),
+            ),
+            IntFunction::Gcd | IntFunction::MulHigh => {
+                Type::function(vec![Type::Integer, Type::Integer], Type::Integer)
+            }
+            IntFunction::GetBit => {
+                Type::function(vec![Type::Integer, Type::Integer], Type::Boolean)
+            }
+            IntFunction::IsPrime => Type::function(vec![Type::Integer], Type::Boolean),
+            IntFunction::Isqrt => Type::function(
+                vec![Type::Integer],
+                Type::result(Type::Integer, Type::String),
+            ),
+            IntFunction::Lcm => Type::function(
+                vec![Type::Integer, Type::Integer],
+                Type::result(Type::Integer, Type::String),
+            ),
+            IntFunction::SetBit => Type::function(
+                vec![Type::Integer, Type::Integer, Type::Boolean],
+                Type::Integer,
+            ),
+            IntFunction::ToBinary
+            | IntFunction::ToHex
+            | IntFunction::ToHexUpper
+            | IntFunction::ToOctal => Type::function(vec![Type::Integer], Type::String),
+            IntFunction::ToggleBit
+            | IntFunction::WrappingAdd
+            | IntFunction::WrappingMul
+            | IntFunction::WrappingSub => {
+                Type::function(vec![Type::Integer, Type::Integer], Type::Integer)
+            }
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        let value = match self {
+            IntFunction::Abs => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+
+                checked_result(integer.checked_abs())
+            }
+            IntFunction::BitAnd => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                Value::Integer(left & right)
+            }
+            IntFunction::BitNot => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+
+                Value::Integer(!integer)
+            }
+            IntFunction::BitOr => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                Value::Integer(left | right)
+            }
+            IntFunction::BitRange => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let (integer, low) = self.integer_and_bit(arguments)?;
+                let high = bit_position(arguments.get(2).unwrap().as_integer()?, 64)?;
+
+                if high < low {
+                    return Err(RuntimeError::InvalidBitPosition {
+                        position: high as i64,
+                    });
+                }
+
+                let width = high - low;
+                let mask = u64::MAX.checked_shr(64 - width).unwrap_or(0);
+
+                Value::Integer(((integer as u64 >> low) & mask) as i64)
+            }
+            IntFunction::BitXor => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                Value::Integer(left ^ right)
+            }
+            IntFunction::CheckedAdd => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                checked_result(left.checked_add(right))
+            }
+            IntFunction::CheckedDiv => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                if right == 0 {
+                    Value::error(Value::string("Division by zero."))
+                } else {
+                    checked_result(left.checked_div(right))
+                }
+            }
+            IntFunction::CheckedMul => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                checked_result(left.checked_mul(right))
+            }
+            IntFunction::CheckedRem => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                if right == 0 {
+                    Value::error(Value::string("Division by zero."))
+                } else {
+                    checked_result(left.checked_rem(right))
+                }
+            }
+            IntFunction::CheckedSub => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                checked_result(left.checked_sub(right))
+            }
+            IntFunction::DivRem => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                if right == 0 {
+                    Value::error(Value::string("Division by zero."))
+                } else if let (Some(quotient), Some(remainder)) =
+                    (left.checked_div(right), left.checked_rem(right))
+                {
+                    Value::ok(Value::List(List::with_items(vec![
+                        Value::Integer(quotient),
+                        Value::Integer(remainder),
+                    ])))
+                } else {
+                    Value::error(Value::string("Integer overflow."))
+                }
+            }
+            IntFunction::Format => {
+                RuntimeError::expect_argument_amount(self.name(), 4, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+                let radix = arguments.get(1).unwrap().as_integer()?;
+                let width = arguments.get(2).unwrap().as_integer()?;
+                let pad = arguments.get(3).unwrap().as_char()?;
+
+                if !(2..=36).contains(&radix) {
+                    return Err(RuntimeError::InvalidRadix { radix });
+                }
+
+                Value::String(format_radix(
+                    integer,
+                    radix as u32,
+                    width.max(0) as usize,
+                    pad,
+                ))
+            }
+            IntFunction::FullMul => {
+                let (left, right) = self.integer_pair(arguments)?;
+                let product = left as i128 * right as i128;
+
+                Value::List(List::with_items(vec![
+                    Value::Integer((product >> 64) as i64),
+                    Value::Integer(product as i64),
+                ]))
+            }
+            IntFunction::Gcd => {
+                let (left, right) = self.integer_pair(arguments)?;
+                let divisor = gcd(left.unsigned_abs(), right.unsigned_abs());
+
+                // Only gcd(-2^63, 0) and gcd(-2^63, -2^63) are out of range.
+                Value::Integer(i64::try_from(divisor).unwrap_or(i64::MAX))
+            }
+            IntFunction::GetBit => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let (integer, position) = self.integer_and_bit(arguments)?;
+
+                Value::Boolean(integer >> position & 1 == 1)
+            }
+            IntFunction::IsPrime => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+
+                Value::Boolean(integer > 1 && is_prime(integer as u64))
+            }
+            IntFunction::Isqrt => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+
+                if integer < 0 {
+                    Value::error(Value::string(
+                        "Cannot take the square root of a negative integer.",
+                    ))
+                } else {
+                    Value::ok(Value::Integer(isqrt(integer as u64) as i64))
+                }
+            }
+            IntFunction::Lcm => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                if left == 0 || right == 0 {
+                    Value::ok(Value::Integer(0))
+                } else {
+                    let divisor = gcd(left.unsigned_abs(), right.unsigned_abs());
+                    let multiple = (left.unsigned_abs() / divisor)
+                        .checked_mul(right.unsigned_abs())
+                        .and_then(|multiple| i64::try_from(multiple).ok());
+
+                    checked_result(multiple)
+                }
+            }
+            IntFunction::MulHigh => {
+                let (left, right) = self.integer_pair(arguments)?;
+                let product = left as i128 * right as i128;
+
+                Value::Integer((product >> 64) as i64)
+            }
+            IntFunction::Pow => {
+                let (base, exponent) = self.integer_pair(arguments)?;
+
+                match u32::try_from(exponent) {
+                    Ok(exponent) => checked_result(base.checked_pow(exponent)),
+                    Err(_) if exponent < 0 => Value::error(Value::string(format!(
+                        "Cannot raise to the power of {exponent}. Use a non-negative number."
+                    ))),
+                    Err(_) => checked_result(match base {
+                        0 | 1 => Some(base),
+                        -1 => Some(if exponent % 2 == 0 { 1 } else { -1 }),
+                        _ => None,
+                    }),
+                }
+            }
+            IntFunction::Sar | IntFunction::Shl | IntFunction::Shr => {
+                let (integer, shift) = self.integer_pair(arguments)?;
+
+                if (0..64).contains(&shift) {
+                    let shifted = match self {
+                        IntFunction::Sar => integer >> shift,
+                        IntFunction::Shl => integer << shift,
+                        _ => (integer as u64 >> shift) as i64,
+                    };
+
+                    Value::ok(Value::Integer(shifted))
+                } else {
+                    Value::error(Value::string(format!(
+                        "Cannot shift by {shift}. Use a number from 0 to 63."
+                    )))
+                }
+            }
+            IntFunction::SetBit => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let (integer, position) = self.integer_and_bit(arguments)?;
+                let value = arguments.get(2).unwrap().as_boolean()?;
+
+                if value {
+                    Value::Integer(integer | 1 << position)
+                } else {
+                    Value::Integer(integer & !(1 << position))
+                }
+            }
+            IntFunction::ToBinary => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+
+                Value::String(format_radix(integer, 2, 0, '0'))
+            }
+            IntFunction::ToHex => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+
+                Value::String(format_radix(integer, 16, 0, '0'))
+            }
+            IntFunction::ToHexUpper => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+
+                Value::String(format_radix(integer, 16, 0, '0').to_uppercase())
+            }
+            IntFunction::ToOctal => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let integer = arguments.first().unwrap().as_integer()?;
+
+                Value::String(format_radix(integer, 8, 0, '0'))
+            }
+            IntFunction::ToggleBit => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let (integer, position) = self.integer_and_bit(arguments)?;
+
+                Value::Integer(integer ^ 1 << position)
+            }
+            IntFunction::WrappingAdd => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                Value::Integer(left.wrapping_add(right))
+            }
+            IntFunction::WrappingMul => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                Value::Integer(left.wrapping_mul(right))
+            }
+            IntFunction::WrappingSub => {
+                let (left, right) = self.integer_pair(arguments)?;
+
+                Value::Integer(left.wrapping_sub(right))
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+fn checked_result(integer: Option<i64>) -> Value {
+    if let Some(integer) = integer {
+        Value::ok(Value::Integer(integer))
+    } else {
+        Value::error(Value::string("Integer overflow."))
+    }
+}
+
+fn bit_position(position: i64, max: i64) -> Result<u32, RuntimeError> {
+    if (0..=max).contains(&position) {
+        Ok(position as u32)
+    } else {
+        Err(RuntimeError::InvalidBitPosition { position })
+    }
+}
+
+/// Euclid's algorithm.
+fn gcd(mut left: u64, mut right: u64) -> u64 {
+    while right != 0 {
+        (left, right) = (right, left % right);
+    }
+
+    left
+}
+
+/// Newton's method on integers, which converges to the floor of the square
+/// root without rounding through floats.
+fn isqrt(integer: u64) -> u64 {
+    if integer < 2 {
+        return integer;
+    }
+
+    let mut root = integer;
+    let mut next = integer / 2 + 1;
+
+    while next < root {
+        root = next;
+        next = (root + integer / root) / 2;
+    }
+
+    root
+}
+
+/// Uses trial division for small integers and a deterministic Miller-Rabin
+/// test for the rest. The witnesses below are enough for every 64-bit integer.
+fn is_prime(integer: u64) -> bool {
+    const TRIAL_DIVISION_LIMIT: u64 = 1 << 20;
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if integer < 2 {
+        return false;
+    }
+
+    if integer < TRIAL_DIVISION_LIMIT {
+        let mut divisor = 2;
+
+        while divisor * divisor <= integer {
+            if integer.is_multiple_of(divisor) {
+                return false;
+            }
+
+            divisor += 1;
+        }
+
+        return true;
+    }
+
+    if WITNESSES
+        .iter()
+        .any(|witness| integer.is_multiple_of(*witness))
+    {
+        return false;
+    }
+
+    let mut odd_part = integer - 1;
+    let mut twos = 0;
+
+    while odd_part.is_multiple_of(2) {
+        odd_part /= 2;
+        twos += 1;
+    }
+
+    'witnesses: for witness in WITNESSES {
+        let mut power = pow_mod(witness, odd_part, integer);
+
+        if power == 1 || power == integer - 1 {
+            continue;
+        }
+
+        for _ in 1..twos {
+            power = mul_mod(power, power, integer);
+
+            if power == integer - 1 {
+                continue 'witnesses;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+fn mul_mod(left: u64, right: u64, modulus: u64) -> u64 {
+    (left as u128 * right as u128 % modulus as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+
+    base %= modulus;
+
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+
+        base = mul_mod(base, base, modulus);
+        exponent /= 2;
+    }
+
+    result
+}
+
+/// Formats the magnitude of `integer` in `radix` with lowercase digits, pads
+/// the digits to `width` with `pad` and prefixes a `-` for negative numbers.
+fn format_radix(integer: i64, radix: u32, width: usize, pad: char) -> String {
+    let mut magnitude = integer.unsigned_abs();
+    let mut digits = Vec::new();
+
+    loop {
+        let digit = (magnitude % radix as u64) as u32;
+
+        digits.push(char::from_digit(digit, radix).unwrap());
+
+        magnitude /= radix as u64;
+
+        if magnitude == 0 {
+            break;
+        }
+    }
+
+    while digits.len() < width {
+        digits.push(pad);
+    }
+
+    if integer < 0 {
+        digits.push('-');
+    }
+
+    digits.iter().rev().collect()
+}
@@ -29,7 +29,7 @@ impl Callable for Json {
         match self {
             Json::Create => "Convert a value to a JSON string.",
             Json::CreatePretty => "Convert a value to a formatted JSON string.",
-            Json::Parse => "Convert JSON to a value",
+            Json::Parse => "Convert JSON to a value.",
         }
     }
 
@@ -52,6 +52,9 @@ impl Callable for Json {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
 
                 let value = arguments.first().unwrap();
+
+                expect_serializable(value)?;
+
                 let json_string = serde_json::to_string(value)?;
 
                 Ok(Value::String(json_string))
@@ -60,6 +63,9 @@ impl Callable for Json {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
 
                 let value = arguments.first().unwrap();
+
+                expect_serializable(value)?;
+
                 let json_string = serde_json::to_string_pretty(value)?;
 
                 Ok(Value::String(json_string))
@@ -75,3 +81,29 @@ impl Callable for Json {
         }
     }
 }
+
+/// Returns an error for the first value in `value` that has no JSON
+/// representation.
+///
+/// `Option::None` becomes `null`, so it is the only enum that is allowed.
+fn expect_serializable(value: &Value) -> Result<(), RuntimeError> {
+    match value {
+        Value::Enum(_) if value.is_none() => Ok(()),
+        Value::Enum(_) | Value::Function(_) => Err(RuntimeError::CannotSerialize {
+            value: value.clone(),
+        }),
+        Value::List(list) => list.items()?.iter().try_for_each(expect_serializable),
+        Value::Map(map) => map.inner().values().try_for_each(expect_serializable),
+        Value::OrderedMap(ordered_map) => ordered_map
+            .inner()?
+            .values()
+            .try_for_each(expect_serializable),
+        Value::Set(set) => set.values()?.iter().try_for_each(expect_serializable),
+        Value::Struct(struct_instance) => struct_instance
+            .map()
+            .inner()
+            .values()
+            .try_for_each(expect_serializable),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,1210 @@
+use std::slice;
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{RuntimeError, ValidationError},
+    Context, Function, Identifier, List, Map, Type, Value,
+};
+
+use super::{str::clamp_index, Callable};
+
+pub fn list_functions() -> impl Iterator<Item = ListFunction> {
+    enum_iterator::all()
+}
+
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ListFunction {
+    All,
+    Any,
+    CartesianProduct,
+    Combinations,
+    Contains,
+    Copy,
+    CopyDeep,
+    Count,
+    CountBy,
+    Dedup,
+    DropWhile,
+    Find,
+    FindIndex,
+    FindLast,
+    First,
+    FlatMapIndexed,
+    Flatten,
+    ForEach,
+    ForEachIndexed,
+    FrequencyMap,
+    Get,
+    GroupBy,
+    Intersperse,
+    IntersperseWith,
+    Last,
+    MapIndexed,
+    Max,
+    Mean,
+    MergeSorted,
+    Min,
+    None,
+    Nth,
+    Partition,
+    Permutations,
+    Pop,
+    PositionOf,
+    Product,
+    Push,
+    Reverse,
+    ReverseInPlace,
+    ScanLeft,
+    Slice,
+    Sliding,
+    Sort,
+    Span,
+    Sum,
+    SumFloat,
+    TakeWhile,
+    ToMap,
+    Transpose,
+    Unique,
+    Zip,
+    ZipWith,
+}
+
+impl ListFunction {
+    /// Returns a copy of the list's items and the function passed after it.
+    ///
+    /// The items are copied so that the function can modify the list without
+    /// waiting on the lock.
+    fn items_and_function<'a>(
+        &self,
+        arguments: &'a [Value],
+    ) -> Result<(Vec<Value>, &'a Function), RuntimeError> {
+        RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+        let items = arguments.first().unwrap().as_list()?.items()?.clone();
+        let function = arguments.get(1).unwrap().as_function()?;
+
+        Ok((items, function))
+    }
+}
+
+impl Callable for ListFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            ListFunction::All => "all",
+            ListFunction::Any => "any",
+            ListFunction::CartesianProduct => "cartesian_product",
+            ListFunction::Combinations => "combinations",
+            ListFunction::Contains => "contains",
+            ListFunction::Copy => "copy",
+            ListFunction::CopyDeep => "copy_deep",
+            ListFunction::Count => "count",
+            ListFunction::CountBy => "count_by",
+            ListFunction::Dedup => "dedup",
+            ListFunction::DropWhile => "drop_while",
+            ListFunction::Find => "find",
+            ListFunction::FindIndex => "find_index",
+            ListFunction::FindLast => "find_last",
+            ListFunction::First => "first",
+            ListFunction::FlatMapIndexed => "flat_map_indexed",
+            ListFunction::Flatten => "flatten",
+            ListFunction::ForEach => "for_each",
+            ListFunction::ForEachIndexed => "for_each_indexed",
+            ListFunction::FrequencyMap => "frequency_map",
+            ListFunction::Get => "get",
+            ListFunction::GroupBy => "group_by",
+            ListFunction::Intersperse => "intersperse",
+            ListFunction::IntersperseWith => "intersperse_with",
+            ListFunction::Last => "last",
+            ListFunction::MapIndexed => "map_indexed",
+            ListFunction::Max => "max",
+            ListFunction::Mean => "mean",
+            ListFunction::MergeSorted => "merge_sorted",
+            ListFunction::Min => "min",
+            ListFunction::None => "none",
+            ListFunction::Nth => "nth",
+            ListFunction::Partition => "partition",
+            ListFunction::Permutations => "permutations",
+            ListFunction::Pop => "pop",
+            ListFunction::PositionOf => "position_of",
+            ListFunction::Product => "product",
+            ListFunction::Push => "push",
+            ListFunction::Reverse => "reverse",
+            ListFunction::ReverseInPlace => "reverse_in_place",
+            ListFunction::ScanLeft => "scan_left",
+            ListFunction::Slice => "slice",
+            ListFunction::Sliding => "sliding",
+            ListFunction::Sort => "sort",
+            ListFunction::Span => "span",
+            ListFunction::Sum => "sum",
+            ListFunction::SumFloat => "sum_float",
+            ListFunction::TakeWhile => "take_while",
+            ListFunction::ToMap => "to_map",
+            ListFunction::Transpose => "transpose",
+            ListFunction::Unique => "unique",
+            ListFunction::Zip => "zip",
+            ListFunction::ZipWith => "zip_with",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            ListFunction::All => "Check if every item satisfies a predicate.",
+            ListFunction::Any => "Check if at least one item satisfies a predicate.",
+            ListFunction::CartesianProduct => {
+                "Pair every item of one list with every item of another."
+            }
+            ListFunction::Combinations => "Get every subset of a size, keeping the items in order.",
+            ListFunction::Contains => "Check if any item is equal to a value.",
+            ListFunction::Copy => "Create a new list with the same items.",
+            ListFunction::CopyDeep => "Create a new list, copying any nested lists as well.",
+            ListFunction::Count => "Count the items that are equal to a value.",
+            ListFunction::CountBy => "Count the items that satisfy a predicate.",
+            ListFunction::Dedup => "Create a new list without consecutive duplicates.",
+            ListFunction::DropWhile => {
+                "Skip the leading items that satisfy a predicate and return the rest."
+            }
+            ListFunction::Find => "Get the first item that satisfies a predicate, if any.",
+            ListFunction::FindIndex => {
+                "Get the index of the first item that satisfies a predicate, or -1."
+            }
+            ListFunction::FindLast => "Get the last item that satisfies a predicate, if any.",
+            ListFunction::First => "Get the first item, or an error if the list is empty.",
+            ListFunction::FlatMapIndexed => {
+                "Concatenate the lists a function returns for each index and item."
+            }
+            ListFunction::Flatten => "Create a new list with the items of nested lists in place of the lists.",
+            ListFunction::ForEach => "Call a function with each item.",
+            ListFunction::ForEachIndexed => "Call a function with each index and item.",
+            ListFunction::FrequencyMap => {
+                "Count how many times each item appears, keyed by its string form."
+            }
+            ListFunction::Get => "Get the item at an index, or None if it is out of bounds.",
+            ListFunction::GroupBy => {
+                "Group items into a map of lists by the key a function returns."
+            }
+            ListFunction::Intersperse => "Create a new list with a separator between the items.",
+            ListFunction::IntersperseWith => {
+                "Create a new list with a separator from a function between the items."
+            }
+            ListFunction::Last => "Get the last item, or an error if the list is empty.",
+            ListFunction::MapIndexed => {
+                "Create a new list from what a function returns for each index and item."
+            }
+            ListFunction::Max => "Get the greatest item, or an error if the list is empty.",
+            ListFunction::Mean => "Get the average of a list of floats.",
+            ListFunction::MergeSorted => "Merge two sorted lists into a new sorted list.",
+            ListFunction::Min => "Get the least item, or an error if the list is empty.",
+            ListFunction::None => "Check if no item satisfies a predicate.",
+            ListFunction::Nth => {
+                "Get the item at an index, counting from the end if the index is negative."
+            }
+            ListFunction::Partition => {
+                "Split items into those that satisfy a predicate and those that do not."
+            }
+            ListFunction::Permutations => "Get every ordering of the items.",
+            ListFunction::Pop => "Remove the last item and return it, if any.",
+            ListFunction::PositionOf => "Get the index of the first item equal to a value, or -1.",
+            ListFunction::Product => "Multiply a list of integers.",
+            ListFunction::Push => "Add an item to the end of a list.",
+            ListFunction::Reverse => "Create a new list with the items in reverse order.",
+            ListFunction::ReverseInPlace => "Reverse the order of a list's items.",
+            ListFunction::ScanLeft => "Accumulate items with a function, keeping every step.",
+            ListFunction::Slice => "Get the items from a start index up to an end index. Negative indexes count from the end.",
+            ListFunction::Sliding => "Get every full window of a size, starting a step apart.",
+            ListFunction::Sort => "Create a new list with the items in ascending order.",
+            ListFunction::Span => "Split a list after the leading items that satisfy a predicate.",
+            ListFunction::Sum => "Add up a list of integers.",
+            ListFunction::SumFloat => "Add up a list of floats.",
+            ListFunction::TakeWhile => "Get the leading items that satisfy a predicate.",
+            ListFunction::ToMap => "Create a map from a list of key-value pairs.",
+            ListFunction::Transpose => "Swap the rows and columns of a list of equal-length lists.",
+            ListFunction::Unique => {
+                "Create a new list without duplicates, keeping first occurrences."
+            }
+            ListFunction::Zip => "Pair the items of two lists, stopping at the end of the shorter one.",
+            ListFunction::ZipWith => "Combine the items of two lists pairwise with a function.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            ListFunction::All | ListFunction::Any | ListFunction::None => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any], Type::Boolean),
+                ],
+                Type::Boolean,
+            ),
+            ListFunction::CartesianProduct => Type::function(
+                vec![Type::list(Type::Any), Type::list(Type::Any)],
+                Type::result(Type::list(Type::list(Type::Any)), Type::String),
+            ),
+            ListFunction::Combinations => Type::function(
+                vec![Type::list(Type::Any), Type::Integer],
+                Type::result(Type::list(Type::list(Type::Any)), Type::String),
+            ),
+            ListFunction::Contains => {
+                Type::function(vec![Type::list(Type::Any), Type::Any], Type::Boolean)
+            }
+            ListFunction::Copy
+            | ListFunction::CopyDeep
+            | ListFunction::Dedup
+            | ListFunction::Flatten
+            | ListFunction::Reverse
+            | ListFunction::Sort
+            | ListFunction::Unique => {
+                Type::function(vec![Type::list(Type::Any)], Type::list(Type::Any))
+            }
+            ListFunction::Count => {
+                Type::function(vec![Type::list(Type::Any), Type::Any], Type::Integer)
+            }
+            ListFunction::CountBy => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any], Type::Boolean),
+                ],
+                Type::Integer,
+            ),
+            ListFunction::DropWhile | ListFunction::TakeWhile => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any], Type::Boolean),
+                ],
+                Type::list(Type::Any),
+            ),
+            ListFunction::Find | ListFunction::FindLast => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any], Type::Boolean),
+                ],
+                Type::option(Some(Type::Any)),
+            ),
+            ListFunction::FindIndex => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any], Type::Boolean),
+                ],
+                Type::Integer,
+            ),
+            ListFunction::First | ListFunction::Last | ListFunction::Max | ListFunction::Min => {
+                Type::function(
+                    vec![Type::list(Type::Any)],
+                    Type::result(Type::Any, Type::String),
+                )
+            }
+            ListFunction::FlatMapIndexed => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Integer, Type::Any], Type::list(Type::Any)),
+                ],
+                Type::list(Type::Any),
+            ),
+            ListFunction::ForEach => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any], Type::Any),
+                ],
+                Type::None,
+            ),
+            ListFunction::ForEachIndexed => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Integer, Type::Any], Type::Any),
+                ],
+                Type::None,
+            ),
+            ListFunction::FrequencyMap => {
+                Type::function(vec![Type::list(Type::Any)], Type::Map(None))
+            }
+            ListFunction::Get => Type::function(
+                vec![Type::list(Type::Any), Type::Integer],
+                Type::option(Some(Type::Any)),
+            ),
+            ListFunction::GroupBy => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any], Type::String),
+                ],
+                Type::Map(None),
+            ),
+            ListFunction::Intersperse => Type::function(
+                vec![Type::list(Type::Any), Type::Any],
+                Type::list(Type::Any),
+            ),
+            ListFunction::IntersperseWith => Type::function(
+                vec![Type::list(Type::Any), Type::function(vec![], Type::Any)],
+                Type::list(Type::Any),
+            ),
+            ListFunction::MapIndexed => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Integer, Type::Any], Type::Any),
+                ],
+                Type::list(Type::Any),
+            ),
+            ListFunction::Mean => Type::function(
+                vec![Type::list(Type::Float)],
+                Type::result(Type::Float, Type::String),
+            ),
+            ListFunction::MergeSorted => Type::function(
+                vec![Type::list(Type::Any), Type::list(Type::Any)],
+                Type::list(Type::Any),
+            ),
+            ListFunction::Nth => Type::function(
+                vec![Type::list(Type::Any), Type::Integer],
+                Type::result(Type::Any, Type::String),
+            ),
+            ListFunction::Partition | ListFunction::Span => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any], Type::Boolean),
+                ],
+                Type::list(Type::list(Type::Any)),
+            ),
+            ListFunction::Permutations => Type::function(
+                vec![Type::list(Type::Any)],
+                Type::result(Type::list(Type::list(Type::Any)), Type::String),
+            ),
+            ListFunction::Pop => {
+                Type::function(vec![Type::list(Type::Any)], Type::option(Some(Type::Any)))
+            }
+            ListFunction::PositionOf => {
+                Type::function(vec![Type::list(Type::Any), Type::Any], Type::Integer)
+            }
+            ListFunction::Product => Type::function(vec![Type::list(Type::Integer)], Type::Integer),
+            ListFunction::Push => {
+                Type::function(vec![Type::list(Type::Any), Type::Any], Type::None)
+            }
+            ListFunction::ReverseInPlace => Type::function(vec![Type::list(Type::Any)], Type::None),
+            ListFunction::ScanLeft => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::Any,
+                    Type::function(vec![Type::Any, Type::Any], Type::Any),
+                ],
+                Type::list(Type::Any),
+            ),
+            ListFunction::Slice => Type::function(
+                vec![Type::list(Type::Any), Type::Integer, Type::Integer],
+                Type::list(Type::Any),
+            ),
+            ListFunction::Sliding => Type::function(
+                vec![Type::list(Type::Any), Type::Integer, Type::Integer],
+                Type::result(Type::list(Type::list(Type::Any)), Type::String),
+            ),
+            ListFunction::Sum => Type::function(vec![Type::list(Type::Integer)], Type::Integer),
+            ListFunction::SumFloat => Type::function(vec![Type::list(Type::Float)], Type::Float),
+            ListFunction::ToMap => Type::function(
+                vec![Type::list(Type::list(Type::Any))],
+                Type::result(Type::Map(None), Type::String),
+            ),
+            ListFunction::Transpose => Type::function(
+                vec![Type::list(Type::list(Type::Any))],
+                Type::list(Type::list(Type::Any)),
+            ),
+            ListFunction::Zip => Type::function(
+                vec![Type::list(Type::Any), Type::list(Type::Any)],
+                Type::list(Type::list(Type::Any)),
+            ),
+            ListFunction::ZipWith => Type::function(
+                vec![
+                    Type::list(Type::Any),
+                    Type::list(Type::Any),
+                    Type::function(vec![Type::Any, Type::Any], Type::Any),
+                ],
+                Type::list(Type::Any),
+            ),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        source: &str,
+        context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        let value = match self {
+            ListFunction::All => {
+                let (items, predicate) = self.items_and_function(arguments)?;
+                let mut all = true;
+
+                for item in items {
+                    if !predicate.call(&[item], source, context)?.as_boolean()? {
+                        all = false;
+
+                        break;
+                    }
+                }
+
+                Value::Boolean(all)
+            }
+            ListFunction::Any => {
+                let (items, predicate) = self.items_and_function(arguments)?;
+
+                Value::Boolean(any(items, predicate, source, context)?)
+            }
+            ListFunction::CartesianProduct => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let left = arguments.first().unwrap().as_list()?.items()?.clone();
+                let right = arguments.get(1).unwrap().as_list()?.items()?.clone();
+                let count = left.len().checked_mul(right.len());
+
+                combinatoric_result(count, || {
+                    let mut pairs = Vec::with_capacity(left.len() * right.len());
+
+                    for left_item in &left {
+                        for right_item in &right {
+                            pairs.push(vec![left_item.clone(), right_item.clone()]);
+                        }
+                    }
+
+                    pairs
+                })
+            }
+            ListFunction::Combinations => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let items = arguments.first().unwrap().as_list()?.items()?.clone();
+                let size = arguments.get(1).unwrap().as_integer()?;
+
+                if size < 0 {
+                    Value::error(Value::string("The combination size cannot be negative."))
+                } else {
+                    let size = size as usize;
+
+                    combinatoric_result(binomial(items.len(), size), || combinations(&items, size))
+                }
+            }
+            ListFunction::Contains => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let value = arguments.get(1).unwrap();
+
+                Value::Boolean(list.items()?.contains(value))
+            }
+            ListFunction::Copy => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+
+                Value::List(List::with_items(list.items()?.clone()))
+            }
+            ListFunction::CopyDeep => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                deep_copy(arguments.first().unwrap())?
+            }
+            ListFunction::Count => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let value = arguments.get(1).unwrap();
+                let count = list.items()?.iter().filter(|item| *item == value).count();
+
+                Value::Integer(count as i64)
+            }
+            ListFunction::CountBy => {
+                let (items, predicate) = self.items_and_function(arguments)?;
+                let mut count = 0;
+
+                for item in items {
+                    if predicate.call(&[item], source, context)?.as_boolean()? {
+                        count += 1;
+                    }
+                }
+
+                Value::Integer(count)
+            }
+            ListFunction::Dedup => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let mut items = arguments.first().unwrap().as_list()?.items()?.clone();
+
+                items.dedup();
+
+                Value::List(List::with_items(items))
+            }
+            ListFunction::DropWhile | ListFunction::Span | ListFunction::TakeWhile => {
+                let (mut items, predicate) = self.items_and_function(arguments)?;
+                let mut prefix_length = 0;
+
+                for item in &items {
+                    if predicate
+                        .call(slice::from_ref(item), source, context)?
+                        .as_boolean()?
+                    {
+                        prefix_length += 1;
+                    } else {
+                        break;
+                    }
+                }
+
+                let rest = items.split_off(prefix_length);
+
+                match self {
+                    ListFunction::DropWhile => Value::List(List::with_items(rest)),
+                    ListFunction::TakeWhile => Value::List(List::with_items(items)),
+                    _ => Value::List(List::with_items(vec![
+                        Value::List(List::with_items(items)),
+                        Value::List(List::with_items(rest)),
+                    ])),
+                }
+            }
+            ListFunction::Find | ListFunction::FindIndex | ListFunction::FindLast => {
+                let (items, predicate) = self.items_and_function(arguments)?;
+                let mut found = None;
+
+                for (index, item) in items.into_iter().enumerate() {
+                    if predicate
+                        .call(slice::from_ref(&item), source, context)?
+                        .as_boolean()?
+                    {
+                        found = Some((index, item));
+
+                        if let ListFunction::Find | ListFunction::FindIndex = self {
+                            break;
+                        }
+                    }
+                }
+
+                match (self, found) {
+                    (ListFunction::FindIndex, Some((index, _))) => Value::Integer(index as i64),
+                    (ListFunction::FindIndex, None) => Value::Integer(-1),
+                    (_, Some((_, item))) => Value::some(item),
+                    (_, None) => Value::none(),
+                }
+            }
+            ListFunction::First => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+
+                if let Some(item) = list.items()?.first() {
+                    Value::ok(item.clone())
+                } else {
+                    Value::error(Value::string("The list is empty."))
+                }
+            }
+            ListFunction::FlatMapIndexed => {
+                let (items, function) = self.items_and_function(arguments)?;
+                let mut flattened = Vec::with_capacity(items.len());
+
+                for (index, item) in items.into_iter().enumerate() {
+                    let index = Value::Integer(index as i64);
+                    let list = function.call(&[index, item], source, context)?;
+
+                    flattened.extend(list.as_list()?.items()?.iter().cloned());
+                }
+
+                Value::List(List::with_items(flattened))
+            }
+            ListFunction::Flatten => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let mut flattened = Vec::new();
+
+                for item in list.items()?.iter() {
+                    if let Value::List(nested) = item {
+                        flattened.extend(nested.items()?.iter().cloned());
+                    } else {
+                        flattened.push(item.clone());
+                    }
+                }
+
+                Value::List(List::with_items(flattened))
+            }
+            ListFunction::ForEach => {
+                let (items, function) = self.items_and_function(arguments)?;
+
+                for item in items {
+                    function.call(&[item], source, context)?;
+                }
+
+                Value::none()
+            }
+            ListFunction::ForEachIndexed => {
+                let (items, function) = self.items_and_function(arguments)?;
+
+                for (index, item) in items.into_iter().enumerate() {
+                    function.call(&[Value::Integer(index as i64), item], source, context)?;
+                }
+
+                Value::none()
+            }
+            ListFunction::FrequencyMap => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let mut frequencies = Map::new();
+
+                for item in list.items()?.iter() {
+                    let key = Identifier::from(item.to_string());
+                    let count = match frequencies.get(&key) {
+                        Some(count) => count.as_integer()?,
+                        None => 0,
+                    };
+
+                    frequencies.set(key, Value::Integer(count + 1));
+                }
+
+                Value::Map(frequencies)
+            }
+            ListFunction::Get => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let index = arguments.get(1).unwrap().as_integer()?;
+                let item = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| list.items().ok()?.get(index).cloned());
+
+                if let Some(item) = item {
+                    Value::some(item)
+                } else {
+                    Value::none()
+                }
+            }
+            ListFunction::GroupBy => {
+                let (items, key_function) = self.items_and_function(arguments)?;
+                let mut groups = Map::new();
+
+                for item in items {
+                    let key_value = key_function.call(slice::from_ref(&item), source, context)?;
+                    let key = Identifier::new(key_value.as_string()?);
+
+                    if let Some(Value::List(group)) = groups.get(&key) {
+                        group.items_mut()?.push(item);
+                    } else {
+                        groups.set(key, Value::List(List::with_items(vec![item])));
+                    }
+                }
+
+                Value::Map(groups)
+            }
+            ListFunction::Intersperse => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let separator = arguments.get(1).unwrap();
+                let items = list.items()?;
+                let mut interspersed = Vec::with_capacity(items.len() * 2);
+
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        interspersed.push(separator.clone());
+                    }
+
+                    interspersed.push(item.clone());
+                }
+
+                Value::List(List::with_items(interspersed))
+            }
+            ListFunction::IntersperseWith => {
+                let (items, separator_function) = self.items_and_function(arguments)?;
+                let mut interspersed = Vec::with_capacity(items.len() * 2);
+
+                for (index, item) in items.into_iter().enumerate() {
+                    if index > 0 {
+                        interspersed.push(separator_function.call(&[], source, context)?);
+                    }
+
+                    interspersed.push(item);
+                }
+
+                Value::List(List::with_items(interspersed))
+            }
+            ListFunction::Last => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+
+                if let Some(item) = list.items()?.last() {
+                    Value::ok(item.clone())
+                } else {
+                    Value::error(Value::string("The list is empty."))
+                }
+            }
+            ListFunction::MapIndexed => {
+                let (items, function) = self.items_and_function(arguments)?;
+                let mut mapped = Vec::with_capacity(items.len());
+
+                for (index, item) in items.into_iter().enumerate() {
+                    mapped.push(function.call(
+                        &[Value::Integer(index as i64), item],
+                        source,
+                        context,
+                    )?);
+                }
+
+                Value::List(List::with_items(mapped))
+            }
+            ListFunction::Max => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+
+                if let Some(item) = list.items()?.iter().max() {
+                    Value::ok(item.clone())
+                } else {
+                    Value::error(Value::string("The list is empty."))
+                }
+            }
+            ListFunction::Mean => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let items = list.items()?;
+
+                if items.is_empty() {
+                    Value::error(Value::string("The list is empty."))
+                } else {
+                    let mut sum = 0.0;
+
+                    for item in items.iter() {
+                        sum += item.as_float()?;
+                    }
+
+                    Value::ok(Value::Float(sum / items.len() as f64))
+                }
+            }
+            ListFunction::MergeSorted => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let left = arguments.first().unwrap().as_list()?.items()?.clone();
+                let right = arguments.get(1).unwrap().as_list()?.items()?.clone();
+                let mut merged = Vec::with_capacity(left.len() + right.len());
+                let mut left = left.into_iter().peekable();
+                let mut right = right.into_iter().peekable();
+
+                // Taking from the left on ties keeps the merge stable.
+                while let (Some(left_item), Some(right_item)) = (left.peek(), right.peek()) {
+                    if right_item < left_item {
+                        merged.extend(right.next());
+                    } else {
+                        merged.extend(left.next());
+                    }
+                }
+
+                merged.extend(left);
+                merged.extend(right);
+
+                Value::List(List::with_items(merged))
+            }
+            ListFunction::Min => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+
+                if let Some(item) = list.items()?.iter().min() {
+                    Value::ok(item.clone())
+                } else {
+                    Value::error(Value::string("The list is empty."))
+                }
+            }
+            ListFunction::None => {
+                let (items, predicate) = self.items_and_function(arguments)?;
+
+                Value::Boolean(!any(items, predicate, source, context)?)
+            }
+            ListFunction::Nth => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let index = arguments.get(1).unwrap().as_integer()?;
+                let items = list.items()?;
+                let length = items.len() as i64;
+                let position = if index < 0 { length + index } else { index };
+
+                if let Some(item) = usize::try_from(position)
+                    .ok()
+                    .and_then(|position| items.get(position))
+                {
+                    Value::ok(item.clone())
+                } else {
+                    Value::error(Value::string(format!(
+                        "Index {index} is out of bounds for a list of length {length}."
+                    )))
+                }
+            }
+            ListFunction::Partition => {
+                let (items, predicate) = self.items_and_function(arguments)?;
+                let mut matching = Vec::new();
+                let mut rest = Vec::new();
+
+                for item in items {
+                    if predicate
+                        .call(slice::from_ref(&item), source, context)?
+                        .as_boolean()?
+                    {
+                        matching.push(item);
+                    } else {
+                        rest.push(item);
+                    }
+                }
+
+                Value::List(List::with_items(vec![
+                    Value::List(List::with_items(matching)),
+                    Value::List(List::with_items(rest)),
+                ]))
+            }
+            ListFunction::Permutations => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let items = arguments.first().unwrap().as_list()?.items()?.clone();
+                let count =
+                    (1..=items.len()).try_fold(1_usize, |count, factor| count.checked_mul(factor));
+
+                combinatoric_result(count, || permutations(&items))
+            }
+            ListFunction::Pop => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let popped = list.items_mut()?.pop();
+
+                if let Some(item) = popped {
+                    Value::some(item)
+                } else {
+                    Value::none()
+                }
+            }
+            ListFunction::PositionOf => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let value = arguments.get(1).unwrap();
+                let position = list.items()?.iter().position(|item| item == value);
+
+                Value::Integer(position.map(|index| index as i64).unwrap_or(-1))
+            }
+            ListFunction::Product => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let mut product = 1i64;
+
+                for item in list.items()?.iter() {
+                    product = product.saturating_mul(item.as_integer()?);
+                }
+
+                Value::Integer(product)
+            }
+            ListFunction::Push => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let item = arguments.get(1).unwrap().clone();
+
+                list.items_mut()?.push(item);
+
+                Value::none()
+            }
+            ListFunction::Reverse => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let reversed = list.items()?.iter().rev().cloned().collect();
+
+                Value::List(List::with_items(reversed))
+            }
+            ListFunction::ReverseInPlace => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+
+                list.items_mut()?.reverse();
+
+                Value::none()
+            }
+            ListFunction::ScanLeft => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let items = arguments.first().unwrap().as_list()?.items()?.clone();
+                let mut accumulator = arguments.get(1).unwrap().clone();
+                let function = arguments.get(2).unwrap().as_function()?;
+                let mut steps = Vec::with_capacity(items.len());
+
+                for item in items {
+                    accumulator = function.call(&[accumulator, item], source, context)?;
+
+                    steps.push(accumulator.clone());
+                }
+
+                Value::List(List::with_items(steps))
+            }
+            ListFunction::Slice => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let items = arguments.first().unwrap().as_list()?.items()?;
+                let start = clamp_index(arguments.get(1).unwrap().as_integer()?, items.len());
+                let end =
+                    clamp_index(arguments.get(2).unwrap().as_integer()?, items.len()).max(start);
+
+                Value::List(List::with_items(items[start..end].to_vec()))
+            }
+            ListFunction::Sliding => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let size = arguments.get(1).unwrap().as_integer()?;
+                let step = arguments.get(2).unwrap().as_integer()?;
+
+                if size <= 0 || step <= 0 {
+                    Value::error(Value::string(
+                        "The window size and step must be greater than zero.",
+                    ))
+                } else {
+                    let windows = list
+                        .items()?
+                        .windows(size as usize)
+                        .step_by(step as usize)
+                        .map(|window| Value::List(List::with_items(window.to_vec())))
+                        .collect();
+
+                    Value::ok(Value::List(List::with_items(windows)))
+                }
+            }
+            ListFunction::Sort => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let mut items = arguments.first().unwrap().as_list()?.items()?.clone();
+
+                items.sort();
+
+                Value::List(List::with_items(items))
+            }
+            ListFunction::Sum => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let mut sum = 0i64;
+
+                for item in list.items()?.iter() {
+                    sum = sum.saturating_add(item.as_integer()?);
+                }
+
+                Value::Integer(sum)
+            }
+            ListFunction::SumFloat => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let mut sum = 0.0;
+
+                for item in list.items()?.iter() {
+                    sum += item.as_float()?;
+                }
+
+                Value::Float(sum)
+            }
+            ListFunction::ToMap => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let pairs = arguments.first().unwrap().as_list()?.items()?.clone();
+                let mut map = Map::new();
+
+                for (index, pair) in pairs.iter().enumerate() {
+                    let pair = match pair {
+                        Value::List(list) => list.items()?.clone(),
+                        _ => Vec::with_capacity(0),
+                    };
+                    let (key, value) = match pair.as_slice() {
+                        [Value::String(key), value] => (key, value),
+                        [_, _] => {
+                            return Ok(Value::error(Value::string(format!(
+                                "The key of pair {index} is not a string."
+                            ))))
+                        }
+                        _ => {
+                            return Ok(Value::error(Value::string(format!(
+                                "Pair {index} does not have exactly two items."
+                            ))))
+                        }
+                    };
+
+                    map.set(Identifier::new(key), value.clone());
+                }
+
+                Value::ok(Value::Map(map))
+            }
+            ListFunction::Transpose => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let rows = arguments.first().unwrap().as_list()?.items()?.clone();
+                let mut columns: Vec<Vec<Value>> = Vec::new();
+
+                for (row_index, row) in rows.iter().enumerate() {
+                    let row_items = row.as_list()?.items()?;
+
+                    if row_index == 0 {
+                        columns = vec![Vec::with_capacity(rows.len()); row_items.len()];
+                    } else if row_items.len() != columns.len() {
+                        return Err(RuntimeError::ValidationFailure(
+                            ValidationError::ExpectedFixedLenList {
+                                expected_len: columns.len(),
+                                actual: row.clone(),
+                            },
+                        ));
+                    }
+
+                    for (column, item) in columns.iter_mut().zip(row_items.iter()) {
+                        column.push(item.clone());
+                    }
+                }
+
+                let columns = columns
+                    .into_iter()
+                    .map(|column| Value::List(List::with_items(column)))
+                    .collect();
+
+                Value::List(List::with_items(columns))
+            }
+            ListFunction::Unique => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let mut unique: Vec<Value> = Vec::new();
+
+                for item in list.items()?.iter() {
+                    if !unique.contains(item) {
+                        unique.push(item.clone());
+                    }
+                }
+
+                Value::List(List::with_items(unique))
+            }
+            ListFunction::Zip => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let left = arguments.first().unwrap().as_list()?.items()?.clone();
+                let right = arguments.get(1).unwrap().as_list()?.items()?.clone();
+                let zipped = left
+                    .into_iter()
+                    .zip(right)
+                    .map(|(left, right)| Value::List(List::with_items(vec![left, right])))
+                    .collect();
+
+                Value::List(List::with_items(zipped))
+            }
+            ListFunction::ZipWith => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let left = arguments.first().unwrap().as_list()?.items()?.clone();
+                let right = arguments.get(1).unwrap().as_list()?.items()?.clone();
+                let function = arguments.get(2).unwrap().as_function()?;
+                let mut zipped = Vec::with_capacity(left.len().min(right.len()));
+
+                for (left, right) in left.into_iter().zip(right) {
+                    zipped.push(function.call(&[left, right], source, context)?);
+                }
+
+                Value::List(List::with_items(zipped))
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+/// The most lists that `cartesian_product`, `combinations` and `permutations`
+/// will create before returning an error instead.
+const MAX_COMBINATORIC_RESULTS: usize = 10_000;
+
+/// Checks the number of results before creating them so that large inputs
+/// return an error instead of running out of memory.
+fn combinatoric_result(count: Option<usize>, create: impl FnOnce() -> Vec<Vec<Value>>) -> Value {
+    if count.is_some_and(|count| count <= MAX_COMBINATORIC_RESULTS) {
+        let lists = create()
+            .into_iter()
+            .map(|items| Value::List(List::with_items(items)))
+            .collect();
+
+        Value::ok(Value::List(List::with_items(lists)))
+    } else {
+        Value::error(Value::string(format!(
+            "The result would have more than {MAX_COMBINATORIC_RESULTS} lists."
+        )))
+    }
+}
+
+fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+
+    (0..k.min(n - k)).try_fold(1_usize, |count, index| {
+        Some(count.checked_mul(n - index)? / (index + 1))
+    })
+}
+
+fn combinations(items: &[Value], size: usize) -> Vec<Vec<Value>> {
+    if size == 0 {
+        return vec![Vec::new()];
+    }
+
+    let mut subsets = Vec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        for mut rest in combinations(&items[index + 1..], size - 1) {
+            rest.insert(0, item.clone());
+            subsets.push(rest);
+        }
+    }
+
+    subsets
+}
+
+fn permutations(items: &[Value]) -> Vec<Vec<Value>> {
+    if items.is_empty() {
+        return vec![Vec::new()];
+    }
+
+    let mut orderings = Vec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        let mut rest = items.to_vec();
+
+        rest.remove(index);
+
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, item.clone());
+            orderings.push(permutation);
+        }
+    }
+
+    orderings
+}
+
+fn deep_copy(value: &Value) -> Result<Value, RuntimeError> {
+    if let Value::List(list) = value {
+        let mut items = Vec::with_capacity(list.items()?.len());
+
+        for item in list.items()?.iter() {
+            items.push(deep_copy(item)?);
+        }
+
+        Ok(Value::List(List::with_items(items)))
+    } else {
+        Ok(value.clone())
+    }
+}
+
+fn any(
+    items: Vec<Value>,
+    predicate: &Function,
+    source: &str,
+    context: &Context,
+) -> Result<bool, RuntimeError> {
+    for item in items {
+        if predicate.call(&[item], source, context)?.as_boolean()? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
@@ -0,0 +1,464 @@
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{RuntimeError, ValidationError},
+    Context, Identifier, List, Map, OrderedMap, Type, Value,
+};
+
+use super::Callable;
+
+pub fn map_functions() -> impl Iterator<Item = MapFunction> {
+    enum_iterator::all()
+}
+
+/// Map tools.
+///
+/// Every function that takes a map also accepts an ordered map. Functions
+/// that change a map return a changed copy and leave the original as it was.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MapFunction {
+    Count,
+    FlatMap,
+    FlatMapList,
+    Get,
+    GetOr,
+    GroupByKey,
+    IsEmpty,
+    Keys,
+    Of,
+    OrderedNew,
+    Remove,
+    Set,
+    ToEntries,
+    ToList,
+    Unzip,
+    Values,
+    Zip,
+    ZipAll,
+}
+
+impl Callable for MapFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            MapFunction::Count => "count",
+            MapFunction::FlatMap => "flat_map",
+            MapFunction::FlatMapList => "flat_map_list",
+            MapFunction::Get => "get",
+            MapFunction::GetOr => "get_or",
+            MapFunction::GroupByKey => "group_by_key",
+            MapFunction::IsEmpty => "is_empty",
+            MapFunction::Keys => "keys",
+            MapFunction::Of => "of",
+            MapFunction::OrderedNew => "ordered_new",
+            MapFunction::Remove => "remove",
+            MapFunction::Set => "set",
+            MapFunction::ToEntries => "to_entries",
+            MapFunction::ToList => "to_list",
+            MapFunction::Unzip => "unzip",
+            MapFunction::Values => "values",
+            MapFunction::Zip => "zip",
+            MapFunction::ZipAll => "zip_all",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            MapFunction::Count => "Get the number of key-value pairs.",
+            MapFunction::FlatMap => {
+                "Merge the maps a function returns for each pair. Later keys win."
+            }
+            MapFunction::FlatMapList => "Concatenate the lists a function returns for each pair.",
+            MapFunction::Get => "Get the value for a key, or None if it is absent.",
+            MapFunction::GetOr => "Get the value for a key, or a default if it is absent.",
+            MapFunction::GroupByKey => "Create a map of lists from a list of key-value pairs.",
+            MapFunction::IsEmpty => "Check if a map has no key-value pairs.",
+            MapFunction::Keys => "Get a list of the keys in iteration order.",
+            MapFunction::Of => "Create a map from alternating keys and values.",
+            MapFunction::OrderedNew => {
+                "Create an insertion-ordered map from alternating keys and values."
+            }
+            MapFunction::Remove => "Get a copy of the map without a key.",
+            MapFunction::Set => "Get a copy of the map with a key set to a value.",
+            MapFunction::ToEntries => "Get a list of maps with a key and a value, sorted by key.",
+            MapFunction::ToList => "Get a list of key-value pairs, sorted by key.",
+            MapFunction::Unzip => "Split a map of two-item lists into two maps with the same keys.",
+            MapFunction::Values => "Get a list of the values in iteration order.",
+            MapFunction::Zip => "Pair the values of the keys that are in both maps.",
+            MapFunction::ZipAll => {
+                "Pair the values of the keys in either map, using a default for missing values."
+            }
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            MapFunction::Count => Type::function(vec![Type::Map(None)], Type::Integer),
+            MapFunction::FlatMap => Type::function(
+                vec![
+                    Type::Map(None),
+                    Type::function(vec![Type::String, Type::Any], Type::Map(None)),
+                ],
+                Type::Map(None),
+            ),
+            MapFunction::FlatMapList => Type::function(
+                vec![
+                    Type::Map(None),
+                    Type::function(vec![Type::String, Type::Any], Type::list(Type::Any)),
+                ],
+                Type::list(Type::Any),
+            ),
+            MapFunction::Get => Type::function(
+                vec![Type::Map(None), Type::String],
+                Type::option(Some(Type::Any)),
+            ),
+            MapFunction::GetOr => {
+                Type::function(vec![Type::Map(None), Type::String, Type::Any], Type::Any)
+            }
+            MapFunction::GroupByKey => {
+                Type::function(vec![Type::list(Type::list(Type::Any))], Type::Map(None))
+            }
+            MapFunction::IsEmpty => Type::function(vec![Type::Map(None)], Type::Boolean),
+            MapFunction::Keys => Type::function(vec![Type::Map(None)], Type::list(Type::String)),
+            MapFunction::Of | MapFunction::OrderedNew => {
+                Type::function(vec![Type::variadic(Type::Any)], Type::Map(None))
+            }
+            MapFunction::Remove => {
+                Type::function(vec![Type::Map(None), Type::String], Type::Map(None))
+            }
+            MapFunction::ToEntries => {
+                Type::function(vec![Type::Map(None)], Type::list(Type::Map(None)))
+            }
+            MapFunction::ToList => Type::function(
+                vec![Type::Map(None)],
+                Type::list(Type::ListExact(vec![Type::String, Type::Any])),
+            ),
+            MapFunction::Unzip => {
+                Type::function(vec![Type::Map(None)], Type::list(Type::Map(None)))
+            }
+            MapFunction::Values => Type::function(vec![Type::Map(None)], Type::list(Type::Any)),
+            MapFunction::Zip => {
+                Type::function(vec![Type::Map(None), Type::Map(None)], Type::Map(None))
+            }
+            MapFunction::ZipAll => Type::function(
+                vec![Type::Map(None), Type::Map(None), Type::Any],
+                Type::Map(None),
+            ),
+            MapFunction::Set => Type::function(
+                vec![Type::Map(None), Type::String, Type::Any],
+                Type::Map(None),
+            ),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        source: &str,
+        context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        let value = match self {
+            MapFunction::Count => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let entries = entries(arguments.first().unwrap())?;
+
+                Value::Integer(entries.len() as i64)
+            }
+            MapFunction::FlatMap | MapFunction::FlatMapList => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let function = arguments.get(1).unwrap().as_function()?;
+                let mut merged = Map::new();
+                let mut concatenated = Vec::new();
+
+                for (key, value) in entries(arguments.first().unwrap())? {
+                    let key = Value::string(key.inner().as_str());
+                    let result = function.call(&[key, value], source, context)?;
+
+                    if let MapFunction::FlatMap = self {
+                        for (key, value) in entries(&result)? {
+                            merged.set(key, value);
+                        }
+                    } else {
+                        concatenated.extend(result.as_list()?.items()?.iter().cloned());
+                    }
+                }
+
+                if let MapFunction::FlatMap = self {
+                    Value::Map(merged)
+                } else {
+                    Value::List(List::with_items(concatenated))
+                }
+            }
+            MapFunction::Get => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let key = arguments.get(1).unwrap().as_string()?;
+
+                if let Some(value) = get(arguments.first().unwrap(), &Identifier::new(key))? {
+                    Value::some(value)
+                } else {
+                    Value::none()
+                }
+            }
+            MapFunction::GetOr => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let key = arguments.get(1).unwrap().as_string()?;
+
+                if let Some(value) = get(arguments.first().unwrap(), &Identifier::new(key))? {
+                    value
+                } else {
+                    arguments.get(2).unwrap().clone()
+                }
+            }
+            MapFunction::GroupByKey => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let pairs = arguments.first().unwrap().as_list()?.items()?.clone();
+                let mut groups = Map::new();
+
+                for pair in pairs {
+                    let (key, value) = match pair.as_list()?.items()?.as_slice() {
+                        [key, value] => (Identifier::new(key.as_string()?), value.clone()),
+                        _ => {
+                            return Err(RuntimeError::ValidationFailure(
+                                ValidationError::ExpectedFixedLenList {
+                                    expected_len: 2,
+                                    actual: pair.clone(),
+                                },
+                            ))
+                        }
+                    };
+
+                    if let Some(Value::List(group)) = groups.get(&key) {
+                        group.items_mut()?.push(value);
+                    } else {
+                        groups.set(key, Value::List(List::with_items(vec![value])));
+                    }
+                }
+
+                Value::Map(groups)
+            }
+            MapFunction::IsEmpty => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let entries = entries(arguments.first().unwrap())?;
+
+                Value::Boolean(entries.is_empty())
+            }
+            MapFunction::Keys => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let keys = entries(arguments.first().unwrap())?
+                    .into_iter()
+                    .map(|(key, _)| Value::string(key.inner().as_str()))
+                    .collect();
+
+                Value::List(List::with_items(keys))
+            }
+            MapFunction::Of | MapFunction::OrderedNew => {
+                let pairs = arguments.chunks_exact(2);
+
+                if !pairs.remainder().is_empty() {
+                    return Err(RuntimeError::ExpectedEvenArgumentAmount {
+                        function_name: self.name().to_string(),
+                        actual: arguments.len(),
+                    });
+                }
+
+                if let MapFunction::OrderedNew = self {
+                    let map = OrderedMap::new();
+
+                    for pair in pairs {
+                        map.set(Identifier::new(pair[0].as_string()?), pair[1].clone())?;
+                    }
+
+                    Value::OrderedMap(map)
+                } else {
+                    let mut map = Map::new();
+
+                    for pair in pairs {
+                        map.set(Identifier::new(pair[0].as_string()?), pair[1].clone());
+                    }
+
+                    Value::Map(map)
+                }
+            }
+            MapFunction::Remove => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let key = Identifier::new(arguments.get(1).unwrap().as_string()?);
+
+                match arguments.first().unwrap() {
+                    Value::OrderedMap(map) => {
+                        let mut entries = map.inner()?.clone();
+
+                        entries.shift_remove(&key);
+
+                        Value::OrderedMap(OrderedMap::with_entries(entries))
+                    }
+                    value => {
+                        let mut entries = value.as_map()?.inner().clone();
+
+                        entries.remove(&key);
+
+                        Value::Map(Map::with_values(entries))
+                    }
+                }
+            }
+            MapFunction::Set => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let key = Identifier::new(arguments.get(1).unwrap().as_string()?);
+                let value = arguments.get(2).unwrap().clone();
+
+                match arguments.first().unwrap() {
+                    Value::OrderedMap(map) => {
+                        let mut entries = map.inner()?.clone();
+
+                        entries.insert(key, value);
+
+                        Value::OrderedMap(OrderedMap::with_entries(entries))
+                    }
+                    map => {
+                        let mut map = map.as_map()?.clone();
+
+                        map.set(key, value);
+
+                        Value::Map(map)
+                    }
+                }
+            }
+            MapFunction::ToEntries | MapFunction::ToList => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let mut entries = entries(arguments.first().unwrap())?;
+
+                entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+                let items = entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let key = Value::string(key.inner().as_str());
+
+                        if let MapFunction::ToEntries = self {
+                            let mut entry = Map::new();
+
+                            entry.set(Identifier::new("key"), key);
+                            entry.set(Identifier::new("value"), value);
+
+                            Value::Map(entry)
+                        } else {
+                            Value::List(List::with_items(vec![key, value]))
+                        }
+                    })
+                    .collect();
+
+                Value::List(List::with_items(items))
+            }
+            MapFunction::Unzip => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let mut left = Map::new();
+                let mut right = Map::new();
+
+                for (key, value) in entries(arguments.first().unwrap())? {
+                    let pair = value.as_list()?.items()?;
+
+                    if let [left_value, right_value] = pair.as_slice() {
+                        left.set(key.clone(), left_value.clone());
+                        right.set(key, right_value.clone());
+                    } else {
+                        return Err(RuntimeError::ValidationFailure(
+                            ValidationError::ExpectedFixedLenList {
+                                expected_len: 2,
+                                actual: value.clone(),
+                            },
+                        ));
+                    }
+                }
+
+                Value::List(List::with_items(vec![Value::Map(left), Value::Map(right)]))
+            }
+            MapFunction::Values => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let values = entries(arguments.first().unwrap())?
+                    .into_iter()
+                    .map(|(_, value)| value)
+                    .collect();
+
+                Value::List(List::with_items(values))
+            }
+            MapFunction::Zip => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let right_map = arguments.get(1).unwrap();
+                let mut zipped = Map::new();
+
+                for (key, left) in entries(arguments.first().unwrap())? {
+                    if let Some(right) = get(right_map, &key)? {
+                        zipped.set(key, Value::List(List::with_items(vec![left, right])));
+                    }
+                }
+
+                Value::Map(zipped)
+            }
+            MapFunction::ZipAll => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let left_map = arguments.first().unwrap();
+                let right_map = arguments.get(1).unwrap();
+                let default = arguments.get(2).unwrap();
+                let mut zipped = Map::new();
+
+                for (key, left) in entries(left_map)? {
+                    let right = get(right_map, &key)?.unwrap_or_else(|| default.clone());
+
+                    zipped.set(key, Value::List(List::with_items(vec![left, right])));
+                }
+
+                for (key, right) in entries(right_map)? {
+                    if get(left_map, &key)?.is_none() {
+                        let pair = vec![default.clone(), right];
+
+                        zipped.set(key, Value::List(List::with_items(pair)));
+                    }
+                }
+
+                Value::Map(zipped)
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+fn get(map: &Value, key: &Identifier) -> Result<Option<Value>, RuntimeError> {
+    if let Value::OrderedMap(ordered_map) = map {
+        Ok(ordered_map.get(key)?)
+    } else {
+        Ok(map.as_map()?.get(key).cloned())
+    }
+}
+
+/// Returns the key-value pairs of either kind of map in iteration order.
+fn entries(map: &Value) -> Result<Vec<(Identifier, Value)>, RuntimeError> {
+    let entries = if let Value::OrderedMap(ordered_map) = map {
+        ordered_map
+            .inner()?
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    } else {
+        map.as_map()?
+            .inner()
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    };
+
+    Ok(entries)
+}
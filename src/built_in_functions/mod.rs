@@ -1,6 +1,20 @@
+pub mod byte;
+pub mod cast;
+pub mod channel;
+pub mod char;
+pub mod date;
+pub mod dir;
+pub mod float;
 pub mod fs;
+pub mod hash;
+pub mod int;
 pub mod json;
+pub mod list;
+pub mod map;
+pub mod set;
 pub mod str;
+pub mod term;
+pub mod url;
 
 use std::fmt::{self, Display, Formatter};
 
@@ -9,10 +23,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     error::{RuntimeError, ValidationError},
-    Context, EnumInstance, Format, Identifier, Type, Value,
+    Context, EnumInstance, Format, Identifier, Map, StructInstance, Type, Value,
 };
 
-use self::{fs::Fs, json::Json, str::StrFunction};
+use self::{
+    byte::ByteFunction, cast::CastFunction, channel::ChannelFunction, char::CharFunction,
+    date::DateFunction, dir::Dir, float::FloatFunction, fs::Fs, hash::HashFunction,
+    int::IntFunction, json::Json, list::ListFunction, map::MapFunction, set::SetFunction,
+    str::StrFunction, term::TermFunction, url::UrlFunction,
+};
 
 pub trait Callable {
     fn name(&self) -> &'static str;
@@ -28,61 +47,136 @@ pub trait Callable {
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum BuiltInFunction {
+    Assert,
     AssertEqual,
+    Byte(ByteFunction),
+    Cast(CastFunction),
+    Char(CharFunction),
+    Channel(ChannelFunction),
+    Date(DateFunction),
+    Dir(Dir),
+    Float(FloatFunction),
     Fs(Fs),
+    Hash(HashFunction),
+    Int(IntFunction),
     Json(Json),
     Length,
+    List(ListFunction),
+    Map(MapFunction),
     Output,
+    Panic,
+    PanicIf,
     RandomBoolean,
     RandomFloat,
     RandomFrom,
     RandomInteger,
+    Set(SetFunction),
     String(StrFunction),
+    Term(TermFunction),
+    Url(UrlFunction),
 }
 
 impl Callable for BuiltInFunction {
     fn name(&self) -> &'static str {
         match self {
+            BuiltInFunction::Assert => "assert",
             BuiltInFunction::AssertEqual => "assert_equal",
+            BuiltInFunction::Byte(byte_function) => byte_function.name(),
+            BuiltInFunction::Cast(cast_function) => cast_function.name(),
+            BuiltInFunction::Char(char_function) => char_function.name(),
+            BuiltInFunction::Channel(channel_function) => channel_function.name(),
+            BuiltInFunction::Date(date_function) => date_function.name(),
+            BuiltInFunction::Dir(dir_function) => dir_function.name(),
+            BuiltInFunction::Float(float_function) => float_function.name(),
             BuiltInFunction::Fs(fs_function) => fs_function.name(),
+            BuiltInFunction::Hash(hash_function) => hash_function.name(),
+            BuiltInFunction::Int(int_function) => int_function.name(),
             BuiltInFunction::Json(json_function) => json_function.name(),
             BuiltInFunction::Length => "length",
+            BuiltInFunction::List(list_function) => list_function.name(),
+            BuiltInFunction::Map(map_function) => map_function.name(),
             BuiltInFunction::Output => "output",
+            BuiltInFunction::Panic => "panic",
+            BuiltInFunction::PanicIf => "panic_if",
             BuiltInFunction::RandomBoolean => "boolean",
             BuiltInFunction::RandomFloat => "float",
             BuiltInFunction::RandomFrom => "from",
             BuiltInFunction::RandomInteger => "integer",
+            BuiltInFunction::Set(set_function) => set_function.name(),
             BuiltInFunction::String(string_function) => string_function.name(),
+            BuiltInFunction::Term(term_function) => term_function.name(),
+            BuiltInFunction::Url(url_function) => url_function.name(),
         }
     }
 
     fn description(&self) -> &'static str {
         match self {
+            BuiltInFunction::Assert => "Stop the program with a message if a condition is false.",
             BuiltInFunction::AssertEqual => "assert_equal",
+            BuiltInFunction::Byte(byte_function) => byte_function.description(),
+            BuiltInFunction::Cast(cast_function) => cast_function.description(),
+            BuiltInFunction::Char(char_function) => char_function.description(),
+            BuiltInFunction::Channel(channel_function) => channel_function.description(),
+            BuiltInFunction::Date(date_function) => date_function.description(),
+            BuiltInFunction::Dir(dir_function) => dir_function.description(),
+            BuiltInFunction::Float(float_function) => float_function.description(),
             BuiltInFunction::Fs(fs_function) => fs_function.description(),
+            BuiltInFunction::Hash(hash_function) => hash_function.description(),
+            BuiltInFunction::Int(int_function) => int_function.description(),
             BuiltInFunction::Json(json_function) => json_function.description(),
             BuiltInFunction::Length => "length",
+            BuiltInFunction::List(list_function) => list_function.description(),
+            BuiltInFunction::Map(map_function) => map_function.description(),
             BuiltInFunction::Output => "output",
+            BuiltInFunction::Panic => "Stop the program with a message.",
+            BuiltInFunction::PanicIf => "Stop the program with a message if a condition is true.",
             BuiltInFunction::RandomBoolean => "boolean",
             BuiltInFunction::RandomFloat => "float",
             BuiltInFunction::RandomFrom => "from",
             BuiltInFunction::RandomInteger => "integer",
+            BuiltInFunction::Set(set_function) => set_function.description(),
             BuiltInFunction::String(string_function) => string_function.description(),
+            BuiltInFunction::Term(term_function) => term_function.description(),
+            BuiltInFunction::Url(url_function) => url_function.description(),
         }
     }
 
     fn r#type(&self) -> Type {
         match self {
+            BuiltInFunction::Assert => Type::function(
+                vec![Type::Boolean, Type::variadic(Type::String)],
+                Type::None,
+            ),
             BuiltInFunction::AssertEqual => Type::function(vec![Type::Any, Type::Any], Type::None),
+            BuiltInFunction::Byte(byte_function) => byte_function.r#type(),
+            BuiltInFunction::Cast(cast_function) => cast_function.r#type(),
+            BuiltInFunction::Char(char_function) => char_function.r#type(),
+            BuiltInFunction::Channel(channel_function) => channel_function.r#type(),
+            BuiltInFunction::Date(date_function) => date_function.r#type(),
+            BuiltInFunction::Dir(dir_function) => dir_function.r#type(),
+            BuiltInFunction::Float(float_function) => float_function.r#type(),
             BuiltInFunction::Fs(fs_function) => fs_function.r#type(),
+            BuiltInFunction::Hash(hash_function) => hash_function.r#type(),
+            BuiltInFunction::Int(int_function) => int_function.r#type(),
             BuiltInFunction::Json(json_function) => json_function.r#type(),
             BuiltInFunction::Length => Type::function(vec![Type::Collection], Type::Integer),
+            BuiltInFunction::List(list_function) => list_function.r#type(),
+            BuiltInFunction::Map(map_function) => map_function.r#type(),
             BuiltInFunction::Output => Type::function(vec![Type::Any], Type::None),
+            BuiltInFunction::Panic => {
+                Type::function(vec![Type::variadic(Type::String)], Type::None)
+            }
+            BuiltInFunction::PanicIf => {
+                Type::function(vec![Type::Boolean, Type::String], Type::None)
+            }
             BuiltInFunction::RandomBoolean => Type::function(vec![], Type::Boolean),
             BuiltInFunction::RandomFloat => Type::function(vec![], Type::Float),
             BuiltInFunction::RandomFrom => Type::function(vec![Type::Collection], Type::Any),
             BuiltInFunction::RandomInteger => Type::function(vec![], Type::Integer),
+            BuiltInFunction::Set(set_function) => set_function.r#type(),
             BuiltInFunction::String(string_function) => string_function.r#type(),
+            BuiltInFunction::Term(term_function) => term_function.r#type(),
+            BuiltInFunction::Url(url_function) => url_function.r#type(),
         }
     }
 
@@ -93,6 +187,26 @@ impl Callable for BuiltInFunction {
         context: &Context,
     ) -> Result<Value, RuntimeError> {
         match self {
+            BuiltInFunction::Assert => {
+                RuntimeError::expect_argument_range(self.name(), 1, 2, arguments.len())?;
+
+                let condition = arguments.first().unwrap().as_boolean()?;
+
+                if condition {
+                    return Ok(Value::none());
+                }
+
+                let message = if let Some(message) = arguments.get(1) {
+                    message.as_string()?.clone()
+                } else {
+                    "assertion failed".to_string()
+                };
+
+                Err(RuntimeError::AssertFailed {
+                    message,
+                    position: None,
+                })
+            }
             BuiltInFunction::AssertEqual => {
                 RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
 
@@ -112,7 +226,20 @@ impl Callable for BuiltInFunction {
                     })
                 }
             }
+            BuiltInFunction::Byte(byte_function) => byte_function.call(arguments, _source, context),
+            BuiltInFunction::Cast(cast_function) => cast_function.call(arguments, _source, context),
+            BuiltInFunction::Char(char_function) => char_function.call(arguments, _source, context),
+            BuiltInFunction::Channel(channel_function) => {
+                channel_function.call(arguments, _source, context)
+            }
+            BuiltInFunction::Date(date_function) => date_function.call(arguments, _source, context),
+            BuiltInFunction::Dir(dir_function) => dir_function.call(arguments, _source, context),
+            BuiltInFunction::Float(float_function) => {
+                float_function.call(arguments, _source, context)
+            }
             BuiltInFunction::Fs(fs_function) => fs_function.call(arguments, _source, context),
+            BuiltInFunction::Hash(hash_function) => hash_function.call(arguments, _source, context),
+            BuiltInFunction::Int(int_function) => int_function.call(arguments, _source, context),
             BuiltInFunction::Json(json_function) => json_function.call(arguments, _source, context),
             BuiltInFunction::Length => {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
@@ -134,6 +261,8 @@ impl Callable for BuiltInFunction {
 
                 Ok(Value::Integer(length as i64))
             }
+            BuiltInFunction::List(list_function) => list_function.call(arguments, _source, context),
+            BuiltInFunction::Map(map_function) => map_function.call(arguments, _source, context),
             BuiltInFunction::Output => {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
 
@@ -143,6 +272,35 @@ impl Callable for BuiltInFunction {
 
                 Ok(Value::none())
             }
+            BuiltInFunction::Panic => {
+                RuntimeError::expect_argument_range(self.name(), 0, 1, arguments.len())?;
+
+                let message = if let Some(message) = arguments.first() {
+                    message.as_string()?.clone()
+                } else {
+                    "explicit panic".to_string()
+                };
+
+                Err(RuntimeError::Panic {
+                    message,
+                    position: None,
+                })
+            }
+            BuiltInFunction::PanicIf => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let condition = arguments.first().unwrap().as_boolean()?;
+                let message = arguments.get(1).unwrap().as_string()?;
+
+                if condition {
+                    Err(RuntimeError::Panic {
+                        message: message.clone(),
+                        position: None,
+                    })
+                } else {
+                    Ok(Value::none())
+                }
+            }
             BuiltInFunction::RandomBoolean => {
                 RuntimeError::expect_argument_amount(self.name(), 0, arguments.len())?;
 
@@ -178,9 +336,12 @@ impl Callable for BuiltInFunction {
 
                 Ok(Value::Integer(random()))
             }
+            BuiltInFunction::Set(set_function) => set_function.call(arguments, _source, context),
             BuiltInFunction::String(string_function) => {
                 string_function.call(arguments, _source, context)
             }
+            BuiltInFunction::Term(term_function) => term_function.call(arguments, _source, context),
+            BuiltInFunction::Url(url_function) => url_function.call(arguments, _source, context),
         }
     }
 }
@@ -196,3 +357,30 @@ impl Display for BuiltInFunction {
         write!(f, "{}", self.name())
     }
 }
+
+/// Creates an opaque handle: a struct instance that refers to a resource kept
+/// in a registry by its id.
+fn handle(name: &str, id: usize) -> Value {
+    let mut map = Map::new();
+
+    map.set(Identifier::new("id"), Value::Integer(id as i64));
+
+    Value::Struct(StructInstance::new(Identifier::new(name), map))
+}
+
+/// Returns the id of a handle created by [handle] with the same name.
+fn handle_id(value: &Value, name: &str) -> Result<usize, RuntimeError> {
+    if let Value::Struct(instance) = value {
+        if instance.name() == &Identifier::new(name) {
+            if let Some(id) = instance.map().get(&Identifier::new("id")) {
+                return Ok(id.as_integer()? as usize);
+            }
+        }
+    }
+
+    Err(ValidationError::ExpectedStructInstance {
+        name: Identifier::new(name),
+        actual: value.clone(),
+    }
+    .into())
+}
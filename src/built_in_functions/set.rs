@@ -0,0 +1,169 @@
+use std::collections::BTreeSet;
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, Identifier, List, Set, Type, Value};
+
+use super::Callable;
+
+pub fn set_functions() -> impl Iterator<Item = SetFunction> {
+    enum_iterator::all()
+}
+
+/// Sorted sets of unique values.
+///
+/// `add` and `remove` change a set in place. The set algebra functions return
+/// a new set and leave their arguments unchanged.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SetFunction {
+    Add,
+    Contains,
+    Difference,
+    FromList,
+    Intersection,
+    New,
+    Remove,
+    ToList,
+    Union,
+}
+
+#[allow(clippy::mutable_key_type)]
+impl SetFunction {
+    /// Returns copies of the two sets passed to a set algebra function.
+    fn set_pair(
+        &self,
+        arguments: &[Value],
+    ) -> Result<(BTreeSet<Value>, BTreeSet<Value>), RuntimeError> {
+        RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+        let left = arguments.first().unwrap().as_set()?.values()?.clone();
+        let right = arguments.get(1).unwrap().as_set()?.values()?.clone();
+
+        Ok((left, right))
+    }
+}
+
+#[allow(clippy::mutable_key_type)]
+impl Callable for SetFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            SetFunction::Add => "add",
+            SetFunction::Contains => "contains",
+            SetFunction::Difference => "difference",
+            SetFunction::FromList => "from_list",
+            SetFunction::Intersection => "intersection",
+            SetFunction::New => "new",
+            SetFunction::Remove => "remove",
+            SetFunction::ToList => "to_list",
+            SetFunction::Union => "union",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            SetFunction::Add => "Add a value to a set.",
+            SetFunction::Contains => "Check if a set contains a value.",
+            SetFunction::Difference => {
+                "Create a set of the values in the first set but not the second."
+            }
+            SetFunction::FromList => "Create a set from the unique items of a list.",
+            SetFunction::Intersection => "Create a set of the values that are in both sets.",
+            SetFunction::New => "Create an empty set.",
+            SetFunction::Remove => "Remove a value from a set. Returns true if it was present.",
+            SetFunction::ToList => "Create a sorted list of a set's values.",
+            SetFunction::Union => "Create a set of the values that are in either set.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        let set = Type::custom(Identifier::new("Set"), Vec::with_capacity(0));
+
+        match self {
+            SetFunction::Add => Type::function(vec![set, Type::Any], Type::None),
+            SetFunction::Contains | SetFunction::Remove => {
+                Type::function(vec![set, Type::Any], Type::Boolean)
+            }
+            SetFunction::Difference | SetFunction::Intersection | SetFunction::Union => {
+                Type::function(vec![set.clone(), set.clone()], set)
+            }
+            SetFunction::FromList => Type::function(vec![Type::list(Type::Any)], set),
+            SetFunction::New => Type::function(vec![], set),
+            SetFunction::ToList => Type::function(vec![set], Type::list(Type::Any)),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        let value = match self {
+            SetFunction::Add => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let set = arguments.first().unwrap().as_set()?;
+                let value = arguments.get(1).unwrap().clone();
+
+                set.values_mut()?.insert(value);
+
+                Value::none()
+            }
+            SetFunction::Contains => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let set = arguments.first().unwrap().as_set()?;
+                let value = arguments.get(1).unwrap();
+
+                Value::Boolean(set.values()?.contains(value))
+            }
+            SetFunction::Difference => {
+                let (left, right) = self.set_pair(arguments)?;
+
+                Value::Set(Set::with_values(&left - &right))
+            }
+            SetFunction::FromList => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let list = arguments.first().unwrap().as_list()?;
+                let values = list.items()?.iter().cloned().collect();
+
+                Value::Set(Set::with_values(values))
+            }
+            SetFunction::Intersection => {
+                let (left, right) = self.set_pair(arguments)?;
+
+                Value::Set(Set::with_values(&left & &right))
+            }
+            SetFunction::New => {
+                RuntimeError::expect_argument_amount(self.name(), 0, arguments.len())?;
+
+                Value::Set(Set::new())
+            }
+            SetFunction::Remove => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let set = arguments.first().unwrap().as_set()?;
+                let value = arguments.get(1).unwrap();
+
+                Value::Boolean(set.values_mut()?.remove(value))
+            }
+            SetFunction::ToList => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let set = arguments.first().unwrap().as_set()?;
+                let items = set.values()?.iter().cloned().collect();
+
+                Value::List(List::with_items(items))
+            }
+            SetFunction::Union => {
+                let (left, right) = self.set_pair(arguments)?;
+
+                Value::Set(Set::with_values(&left | &right))
+            }
+        };
+
+        Ok(value)
+    }
+}
@@ -1,7 +1,13 @@
+use std::{cmp::Ordering, iter::Peekable, str::Chars};
+
 use enum_iterator::Sequence;
 use serde::{Deserialize, Serialize};
+use unicode_normalization::{is_nfc, is_nfd, UnicodeNormalization};
 
-use crate::{error::RuntimeError, Context, EnumInstance, Identifier, List, Type, Value};
+use crate::{
+    error::{RuntimeError, ValidationError},
+    Context, EnumInstance, Identifier, List, Map, StructInstance, Type, Value,
+};
 
 use super::Callable;
 
@@ -12,17 +18,48 @@ pub fn string_functions() -> impl Iterator<Item = StrFunction> {
 #[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum StrFunction {
     AsBytes,
+    BuilderFinish,
+    BuilderLen,
+    BuilderNew,
+    BuilderPush,
+    BuilderPushChar,
+    Compare,
+    CompareBytes,
+    CompareIgnoreCase,
+    Contains,
     EndsWith,
+    EqualsIgnoreCase,
     Find,
+    IndexOf,
+    IndexOfChar,
     Insert,
+    IsAlpha,
+    IsAlphanumeric,
     IsAscii,
     IsEmpty,
+    IsLowercase,
+    IsNfc,
+    IsNfd,
+    IsNumeric,
+    IsUppercase,
+    IsWhitespace,
+    Join,
+    LastIndexOf,
     Lines,
     Matches,
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
     Parse,
     Remove,
+    Repeat,
+    Replace,
     ReplaceRange,
     Retain,
+    RsplitOnce,
+    Slice,
+    SliceBytes,
     Split,
     SplitAt,
     SplitInclusive,
@@ -30,8 +67,11 @@ pub enum StrFunction {
     SplitOnce,
     SplitTerminator,
     SplitWhitespace,
+    Sprintf,
     StartsWith,
     StripPrefix,
+    ToAsciiLowercase,
+    ToAsciiUppercase,
     ToLowercase,
     ToUppercase,
     Trim,
@@ -41,23 +81,55 @@ pub enum StrFunction {
     TrimStart,
     TrimStartMatches,
     Truncate,
+    Words,
 }
 
 impl Callable for StrFunction {
     fn name(&self) -> &'static str {
         match self {
             StrFunction::AsBytes => "as_bytes",
+            StrFunction::BuilderFinish => "builder_finish",
+            StrFunction::BuilderLen => "builder_len",
+            StrFunction::BuilderNew => "builder_new",
+            StrFunction::BuilderPush => "builder_push",
+            StrFunction::BuilderPushChar => "builder_push_char",
+            StrFunction::Compare => "compare",
+            StrFunction::CompareBytes => "compare_bytes",
+            StrFunction::CompareIgnoreCase => "compare_ignore_case",
+            StrFunction::Contains => "contains",
             StrFunction::EndsWith => "ends_with",
+            StrFunction::EqualsIgnoreCase => "equals_ignore_case",
             StrFunction::Find => "find",
+            StrFunction::IndexOf => "index_of",
+            StrFunction::IndexOfChar => "index_of_char",
             StrFunction::Insert => "insert",
+            StrFunction::IsAlpha => "is_alpha",
+            StrFunction::IsAlphanumeric => "is_alphanumeric",
             StrFunction::IsAscii => "is_ascii",
             StrFunction::IsEmpty => "is_empty",
+            StrFunction::IsLowercase => "is_lowercase",
+            StrFunction::IsNfc => "is_nfc",
+            StrFunction::IsNfd => "is_nfd",
+            StrFunction::IsNumeric => "is_numeric",
+            StrFunction::IsUppercase => "is_uppercase",
+            StrFunction::IsWhitespace => "is_whitespace",
+            StrFunction::Join => "join",
+            StrFunction::LastIndexOf => "last_index_of",
             StrFunction::Lines => "lines",
             StrFunction::Matches => "matches",
+            StrFunction::Nfc => "nfc",
+            StrFunction::Nfd => "nfd",
+            StrFunction::Nfkc => "nfkc",
+            StrFunction::Nfkd => "nfkd",
             StrFunction::Parse => "parse",
             StrFunction::Remove => "remove",
+            StrFunction::Repeat => "repeat",
+            StrFunction::Replace => "replace",
             StrFunction::ReplaceRange => "replace_range",
             StrFunction::Retain => "retain",
+            StrFunction::RsplitOnce => "rsplit_once",
+            StrFunction::Slice => "slice",
+            StrFunction::SliceBytes => "slice_bytes",
             StrFunction::Split => "split",
             StrFunction::SplitAt => "split_at",
             StrFunction::SplitInclusive => "split_inclusive",
@@ -65,8 +137,11 @@ impl Callable for StrFunction {
             StrFunction::SplitOnce => "split_once",
             StrFunction::SplitTerminator => "split_terminator",
             StrFunction::SplitWhitespace => "split_whitespace",
+            StrFunction::Sprintf => "sprintf",
             StrFunction::StartsWith => "starts_with",
             StrFunction::StripPrefix => "strip_prefix",
+            StrFunction::ToAsciiLowercase => "to_ascii_lowercase",
+            StrFunction::ToAsciiUppercase => "to_ascii_uppercase",
             StrFunction::ToLowercase => "to_lowercase",
             StrFunction::ToUppercase => "to_uppercase",
             StrFunction::Trim => "trim",
@@ -76,47 +151,122 @@ impl Callable for StrFunction {
             StrFunction::TrimStart => "trim_start",
             StrFunction::TrimStartMatches => "trim_start_matches",
             StrFunction::Truncate => "truncate",
+            StrFunction::Words => "words",
         }
     }
 
     fn description(&self) -> &'static str {
         match self {
             StrFunction::AsBytes => "TODO",
-            StrFunction::EndsWith => "TODO",
-            StrFunction::Find => "TODO",
+            StrFunction::BuilderFinish => {
+                "Get a builder's string and leave the builder empty."
+            }
+            StrFunction::BuilderLen => "Get the number of characters in a builder.",
+            StrFunction::BuilderNew => "Create an empty string builder.",
+            StrFunction::BuilderPush => "Append a string to a builder.",
+            StrFunction::BuilderPushChar => "Append a character to a builder.",
+            StrFunction::Compare => {
+                "Compare two strings after Unicode normalization. Returns -1, 0 or 1."
+            }
+            StrFunction::CompareBytes => "Compare the UTF-8 bytes of two strings. Returns -1, 0 or 1.",
+            StrFunction::CompareIgnoreCase => {
+                "Compare two strings, ignoring case. Returns -1, 0 or 1."
+            }
+            StrFunction::Contains => "Check if a string contains a substring.",
+            StrFunction::EndsWith => "Check if a string ends with a pattern.",
+            StrFunction::EqualsIgnoreCase => "Check if two strings are equal, ignoring case.",
+            StrFunction::Find => "Get the byte offset of the first match of a pattern as an option.",
+            StrFunction::IndexOf => {
+                "Get the byte offset of the first occurrence of a substring, or -1."
+            }
+            StrFunction::IndexOfChar => {
+                "Get the byte offset of the first occurrence of a character, or -1."
+            }
             StrFunction::Insert => "TODO",
+            StrFunction::IsAlpha => "Check if a string is not empty and every character is alphabetic.",
+            StrFunction::IsAlphanumeric => {
+                "Check if a string is not empty and every character is alphabetic or a digit."
+            }
             StrFunction::IsAscii => "TODO",
-            StrFunction::IsEmpty => "TODO",
-            StrFunction::Lines => "TODO",
+            StrFunction::IsEmpty => "Check if a string has no characters.",
+            StrFunction::IsLowercase => "Check if every cased character is lowercase.",
+            StrFunction::IsNfc => "Check if a string is in Unicode normalization form C.",
+            StrFunction::IsNfd => "Check if a string is in Unicode normalization form D.",
+            StrFunction::IsNumeric => "Check if a string is not empty and every character is an ASCII digit.",
+            StrFunction::IsUppercase => "Check if every cased character is uppercase.",
+            StrFunction::IsWhitespace => {
+                "Check if a string is not empty and every character is whitespace."
+            }
+            StrFunction::Join => "Join a list of strings with a separator between them.",
+            StrFunction::LastIndexOf => {
+                "Get the byte offset of the last occurrence of a substring, or -1."
+            }
+            StrFunction::Lines => "Split a string on line breaks, including \\r\\n.",
             StrFunction::Matches => "TODO",
+            StrFunction::Nfc => "Normalize a string to Unicode normalization form C.",
+            StrFunction::Nfd => "Normalize a string to Unicode normalization form D.",
+            StrFunction::Nfkc => "Normalize a string to Unicode normalization form KC.",
+            StrFunction::Nfkd => "Normalize a string to Unicode normalization form KD.",
             StrFunction::Parse => "TODO",
             StrFunction::Remove => "TODO",
+            StrFunction::Repeat => "Repeat a string a number of times.",
+            StrFunction::Replace => "Replace every match of a pattern with another string.",
             StrFunction::ReplaceRange => "TODO",
             StrFunction::Retain => "TODO",
-            StrFunction::Split => "TODO",
-            StrFunction::SplitAt => "TODO",
+            StrFunction::RsplitOnce => {
+                "Split a string at the last match of a pattern, or return an error."
+            }
+            StrFunction::Slice => {
+                "Get the characters from a start index up to an end index. Negative indexes count from the end."
+            }
+            StrFunction::SliceBytes => {
+                "Get the bytes from a start offset up to an end offset. Negative offsets count from the end."
+            }
+            StrFunction::Split => "Split a string on every match of a pattern.",
+            StrFunction::SplitAt => "Split a string in two at a byte index.",
             StrFunction::SplitInclusive => "TODO",
-            StrFunction::SplitN => "TODO",
-            StrFunction::SplitOnce => "TODO",
+            StrFunction::SplitN => {
+                "Split a string into at most a number of parts. The last part holds the rest."
+            }
+            StrFunction::SplitOnce => {
+                "Split a string at the first match of a pattern, or return an error."
+            }
             StrFunction::SplitTerminator => "TODO",
             StrFunction::SplitWhitespace => "TODO",
-            StrFunction::StartsWith => "TODO",
+            StrFunction::Sprintf => "Format values with printf-style specifiers.",
+            StrFunction::StartsWith => "Check if a string starts with a pattern.",
             StrFunction::StripPrefix => "TODO",
-            StrFunction::ToLowercase => "TODO",
-            StrFunction::ToUppercase => "TODO",
-            StrFunction::Trim => "TODO",
-            StrFunction::TrimEnd => "TODO",
+            StrFunction::ToAsciiLowercase => "Convert ASCII letters to lowercase.",
+            StrFunction::ToAsciiUppercase => "Convert ASCII letters to uppercase.",
+            StrFunction::ToLowercase => "Convert every character to lowercase.",
+            StrFunction::ToUppercase => "Convert every character to uppercase.",
+            StrFunction::Trim => "Remove leading and trailing whitespace.",
+            StrFunction::TrimEnd => "Remove trailing whitespace.",
             StrFunction::TrimEndMatches => "TODO",
             StrFunction::TrimMatches => "TODO",
-            StrFunction::TrimStart => "TODO",
+            StrFunction::TrimStart => "Remove leading whitespace.",
             StrFunction::TrimStartMatches => "TODO",
             StrFunction::Truncate => "TODO",
+            StrFunction::Words => "Split a string on runs of whitespace.",
         }
     }
 
     fn r#type(&self) -> Type {
+        let builder = Type::custom(Identifier::new("StrBuilder"), Vec::with_capacity(0));
+
         match self {
             StrFunction::AsBytes => Type::function(vec![Type::String], Type::list(Type::Integer)),
+            StrFunction::BuilderFinish => Type::function(vec![builder], Type::String),
+            StrFunction::BuilderLen => Type::function(vec![builder], Type::Integer),
+            StrFunction::BuilderNew => Type::function(vec![], builder),
+            StrFunction::BuilderPush => Type::function(vec![builder, Type::String], Type::None),
+            StrFunction::BuilderPushChar => Type::function(vec![builder, Type::String], Type::None),
+            StrFunction::Compare | StrFunction::CompareBytes | StrFunction::CompareIgnoreCase => {
+                Type::function(vec![Type::String, Type::String], Type::Integer)
+            }
+            StrFunction::Contains | StrFunction::EqualsIgnoreCase => {
+                Type::function(vec![Type::String, Type::String], Type::Boolean)
+            }
             StrFunction::EndsWith => {
                 Type::function(vec![Type::String, Type::String], Type::Boolean)
             }
@@ -124,21 +274,43 @@ impl Callable for StrFunction {
                 vec![Type::String, Type::String],
                 Type::option(Some(Type::Integer)),
             ),
+            StrFunction::IndexOf | StrFunction::IndexOfChar | StrFunction::LastIndexOf => {
+                Type::function(vec![Type::String, Type::String], Type::Integer)
+            }
             StrFunction::Insert => Type::function(
                 vec![Type::String, Type::Integer, Type::String],
                 Type::String,
             ),
+            StrFunction::IsAlpha
+            | StrFunction::IsAlphanumeric
+            | StrFunction::IsNumeric
+            | StrFunction::IsWhitespace => Type::function(vec![Type::String], Type::Boolean),
             StrFunction::IsAscii => Type::function(vec![Type::String], Type::Boolean),
             StrFunction::IsEmpty => Type::function(vec![Type::String], Type::Boolean),
+            StrFunction::IsLowercase => Type::function(vec![Type::String], Type::Boolean),
+            StrFunction::IsNfc | StrFunction::IsNfd => {
+                Type::function(vec![Type::String], Type::Boolean)
+            }
+            StrFunction::IsUppercase => Type::function(vec![Type::String], Type::Boolean),
+            StrFunction::Join => {
+                Type::function(vec![Type::list(Type::String), Type::String], Type::String)
+            }
             StrFunction::Lines => Type::function(vec![Type::String], Type::list(Type::String)),
             StrFunction::Matches => {
                 Type::function(vec![Type::String, Type::String], Type::list(Type::String))
             }
+            StrFunction::Nfc | StrFunction::Nfd | StrFunction::Nfkc | StrFunction::Nfkd => {
+                Type::function(vec![Type::String], Type::String)
+            }
             StrFunction::Parse => Type::function(vec![Type::String], Type::Any),
             StrFunction::Remove => Type::function(
                 vec![Type::String, Type::Integer],
                 Type::option(Some(Type::String)),
             ),
+            StrFunction::Repeat => Type::function(vec![Type::String, Type::Integer], Type::String),
+            StrFunction::Replace => {
+                Type::function(vec![Type::String, Type::String, Type::String], Type::String)
+            }
             StrFunction::ReplaceRange => Type::function(
                 vec![Type::String, Type::list(Type::Integer), Type::String],
                 Type::String,
@@ -150,6 +322,10 @@ impl Callable for StrFunction {
                 ],
                 Type::String,
             ),
+            StrFunction::Slice | StrFunction::SliceBytes => Type::function(
+                vec![Type::String, Type::Integer, Type::Integer],
+                Type::String,
+            ),
             StrFunction::Split => {
                 Type::function(vec![Type::String, Type::String], Type::list(Type::String))
             }
@@ -163,15 +339,19 @@ impl Callable for StrFunction {
                 vec![Type::String, Type::Integer, Type::String],
                 Type::list(Type::String),
             ),
-            StrFunction::SplitOnce => {
-                Type::function(vec![Type::String, Type::String], Type::list(Type::String))
-            }
+            StrFunction::RsplitOnce | StrFunction::SplitOnce => Type::function(
+                vec![Type::String, Type::String],
+                Type::result(Type::list(Type::String), Type::String),
+            ),
             StrFunction::SplitTerminator => {
                 Type::function(vec![Type::String, Type::String], Type::list(Type::String))
             }
             StrFunction::SplitWhitespace => {
                 Type::function(vec![Type::String], Type::list(Type::String))
             }
+            StrFunction::Sprintf => {
+                Type::function(vec![Type::String, Type::variadic(Type::Any)], Type::String)
+            }
             StrFunction::StartsWith => {
                 Type::function(vec![Type::String, Type::String], Type::Boolean)
             }
@@ -179,11 +359,14 @@ impl Callable for StrFunction {
                 vec![Type::String, Type::String],
                 Type::option(Some(Type::String)),
             ),
+            StrFunction::ToAsciiLowercase => Type::function(vec![Type::String], Type::String),
+            StrFunction::ToAsciiUppercase => Type::function(vec![Type::String], Type::String),
             StrFunction::ToLowercase => Type::function(vec![Type::String], Type::String),
             StrFunction::ToUppercase => Type::function(vec![Type::String], Type::String),
             StrFunction::Truncate => {
                 Type::function(vec![Type::String, Type::Integer], Type::String)
             }
+            StrFunction::Words => Type::function(vec![Type::String], Type::list(Type::String)),
             StrFunction::Trim => Type::function(vec![Type::String], Type::String),
             StrFunction::TrimEnd => Type::function(vec![Type::String], Type::String),
             StrFunction::TrimEndMatches => {
@@ -217,6 +400,82 @@ impl Callable for StrFunction {
 
                 Value::List(List::with_items(bytes))
             }
+            StrFunction::BuilderFinish => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = with_builder(arguments.first().unwrap(), std::mem::take)?;
+
+                Value::String(string)
+            }
+            StrFunction::BuilderLen => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let length =
+                    with_builder(arguments.first().unwrap(), |string| string.chars().count())?;
+
+                Value::Integer(length as i64)
+            }
+            StrFunction::BuilderNew => {
+                RuntimeError::expect_argument_amount(self.name(), 0, arguments.len())?;
+
+                let mut map = Map::new();
+
+                map.set(
+                    Identifier::new("buffer"),
+                    Value::List(List::with_items(vec![Value::string("")])),
+                );
+
+                Value::Struct(StructInstance::new(Identifier::new("StrBuilder"), map))
+            }
+            StrFunction::BuilderPush | StrFunction::BuilderPushChar => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let argument = arguments.get(1).unwrap();
+
+                if let StrFunction::BuilderPushChar = self {
+                    let char = argument.as_char()?;
+
+                    with_builder(arguments.first().unwrap(), |string| string.push(char))?;
+                } else {
+                    let pushed = argument.as_string()?;
+
+                    with_builder(arguments.first().unwrap(), |string| string.push_str(pushed))?;
+                }
+
+                Value::none()
+            }
+            StrFunction::Compare
+            | StrFunction::CompareBytes
+            | StrFunction::CompareIgnoreCase
+            | StrFunction::EqualsIgnoreCase => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let left = arguments.first().unwrap().as_string()?;
+                let right = arguments.get(1).unwrap().as_string()?;
+                let ordering = match self {
+                    StrFunction::Compare => left.nfc().cmp(right.nfc()),
+                    StrFunction::CompareBytes => left.as_bytes().cmp(right.as_bytes()),
+                    _ => left
+                        .nfc()
+                        .flat_map(char::to_lowercase)
+                        .cmp(right.nfc().flat_map(char::to_lowercase)),
+                };
+
+                if let StrFunction::EqualsIgnoreCase = self {
+                    Value::Boolean(ordering == Ordering::Equal)
+                } else {
+                    Value::Integer(ordering as i64)
+                }
+            }
+            StrFunction::Contains => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+                let pattern_string = arguments.get(1).unwrap().as_string()?;
+                let pattern = pattern_string.as_str();
+
+                Value::Boolean(string.contains(pattern))
+            }
             StrFunction::EndsWith => {
                 RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
 
@@ -250,6 +509,41 @@ impl Callable for StrFunction {
                     ))
                 }
             }
+            StrFunction::IndexOf => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let haystack = arguments.first().unwrap().as_string()?;
+                let needle = arguments.get(1).unwrap().as_string()?;
+
+                byte_offset(haystack.find(needle.as_str()))
+            }
+            StrFunction::IndexOfChar => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let haystack = arguments.first().unwrap().as_string()?;
+                let needle = arguments.get(1).unwrap().as_char()?;
+
+                byte_offset(haystack.find(needle))
+            }
+            StrFunction::IsAlpha => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                Value::Boolean(!string.is_empty() && string.chars().all(char::is_alphabetic))
+            }
+            StrFunction::IsAlphanumeric => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                Value::Boolean(
+                    !string.is_empty()
+                        && string
+                            .chars()
+                            .all(|char| char.is_alphabetic() || char.is_ascii_digit()),
+                )
+            }
             StrFunction::IsAscii => {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
 
@@ -264,6 +558,65 @@ impl Callable for StrFunction {
 
                 Value::Boolean(string.is_empty())
             }
+            StrFunction::IsLowercase => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                Value::Boolean(!string.chars().any(char::is_uppercase))
+            }
+            StrFunction::IsNfc | StrFunction::IsNfd => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+                let is_normalized = if let StrFunction::IsNfc = self {
+                    is_nfc(string)
+                } else {
+                    is_nfd(string)
+                };
+
+                Value::Boolean(is_normalized)
+            }
+            StrFunction::IsNumeric => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                Value::Boolean(
+                    !string.is_empty() && string.chars().all(|char| char.is_ascii_digit()),
+                )
+            }
+            StrFunction::IsUppercase => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                Value::Boolean(!string.chars().any(char::is_lowercase))
+            }
+            StrFunction::IsWhitespace => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                Value::Boolean(!string.is_empty() && string.chars().all(char::is_whitespace))
+            }
+            StrFunction::Join => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let items = arguments.first().unwrap().as_list()?.items()?;
+                let separator = arguments.get(1).unwrap().as_string()?;
+                let mut joined = String::new();
+
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        joined.push_str(separator);
+                    }
+
+                    joined.push_str(item.as_string()?);
+                }
+
+                Value::String(joined)
+            }
             StrFunction::Insert => {
                 RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
 
@@ -275,6 +628,14 @@ impl Callable for StrFunction {
 
                 Value::String(string)
             }
+            StrFunction::LastIndexOf => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let haystack = arguments.first().unwrap().as_string()?;
+                let needle = arguments.get(1).unwrap().as_string()?;
+
+                byte_offset(haystack.rfind(needle.as_str()))
+            }
             StrFunction::Lines => {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
 
@@ -299,6 +660,19 @@ impl Callable for StrFunction {
 
                 Value::List(List::with_items(matches))
             }
+            StrFunction::Nfc | StrFunction::Nfd | StrFunction::Nfkc | StrFunction::Nfkd => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+                let normalized = match self {
+                    StrFunction::Nfc => string.nfc().collect(),
+                    StrFunction::Nfd => string.nfd().collect(),
+                    StrFunction::Nfkc => string.nfkc().collect(),
+                    _ => string.nfkd().collect(),
+                };
+
+                Value::String(normalized)
+            }
             StrFunction::Parse => {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
 
@@ -330,6 +704,24 @@ impl Callable for StrFunction {
                     Value::none()
                 }
             }
+            StrFunction::Repeat => {
+                RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+                let count = arguments.get(1).unwrap().as_integer()?.max(0) as usize;
+
+                Value::String(string.repeat(count))
+            }
+            StrFunction::Replace => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+                let pattern_string = arguments.get(1).unwrap().as_string()?;
+                let pattern = pattern_string.as_str();
+                let replacement = arguments.get(2).unwrap().as_string()?;
+
+                Value::String(string.replace(pattern, replacement))
+            }
             StrFunction::ReplaceRange => {
                 RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
 
@@ -361,6 +753,37 @@ impl Callable for StrFunction {
 
                 // Value::String(string)
             }
+            StrFunction::Slice => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+                let length = string.chars().count();
+                let start = clamp_index(arguments.get(1).unwrap().as_integer()?, length);
+                let end = clamp_index(arguments.get(2).unwrap().as_integer()?, length);
+                let slice = string
+                    .chars()
+                    .skip(start)
+                    .take(end.saturating_sub(start))
+                    .collect();
+
+                Value::String(slice)
+            }
+            StrFunction::SliceBytes => {
+                RuntimeError::expect_argument_amount(self.name(), 3, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+                let start = clamp_index(arguments.get(1).unwrap().as_integer()?, string.len());
+                let end =
+                    clamp_index(arguments.get(2).unwrap().as_integer()?, string.len()).max(start);
+
+                for index in [start, end] {
+                    if !string.is_char_boundary(index) {
+                        return Err(RuntimeError::InvalidCharBoundary { index });
+                    }
+                }
+
+                Value::string(&string[start..end])
+            }
             StrFunction::Split => {
                 RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
 
@@ -413,23 +836,27 @@ impl Callable for StrFunction {
 
                 Value::List(List::with_items(sections))
             }
-            StrFunction::SplitOnce => {
+            StrFunction::RsplitOnce | StrFunction::SplitOnce => {
                 RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
 
                 let string = arguments.first().unwrap().as_string()?;
                 let pattern_string = arguments.get(1).unwrap().as_string()?;
                 let pattern = pattern_string.as_str();
-                let sections = string.split_once(pattern).map(|(left, right)| {
-                    Value::List(List::with_items(vec![
+                let sections = if let StrFunction::SplitOnce = self {
+                    string.split_once(pattern)
+                } else {
+                    string.rsplit_once(pattern)
+                };
+
+                if let Some((left, right)) = sections {
+                    Value::ok(Value::List(List::with_items(vec![
                         Value::string(left.to_string()),
                         Value::string(right.to_string()),
-                    ]))
-                });
-
-                if let Some(sections) = sections {
-                    Value::some(sections)
+                    ])))
                 } else {
-                    Value::none()
+                    Value::error(Value::string(format!(
+                        "The pattern \"{pattern}\" was not found."
+                    )))
                 }
             }
             StrFunction::SplitTerminator => {
@@ -456,6 +883,20 @@ impl Callable for StrFunction {
 
                 Value::List(List::with_items(sections))
             }
+            StrFunction::Sprintf => {
+                let format = if let Some(format) = arguments.first() {
+                    format.as_string()?
+                } else {
+                    return Err(RuntimeError::ExpectedBuiltInFunctionArgumentAmount {
+                        function_name: self.name().to_string(),
+                        expected: 1,
+                        actual: 0,
+                    });
+                };
+                let formatted = sprintf(self.name(), format, &arguments[1..])?;
+
+                Value::String(formatted)
+            }
             StrFunction::StartsWith => {
                 RuntimeError::expect_argument_amount(self.name(), 2, arguments.len())?;
 
@@ -485,6 +926,20 @@ impl Callable for StrFunction {
                     Value::none()
                 }
             }
+            StrFunction::ToAsciiLowercase => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                Value::string(string.to_ascii_lowercase())
+            }
+            StrFunction::ToAsciiUppercase => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+
+                Value::string(string.to_ascii_uppercase())
+            }
             StrFunction::ToLowercase => {
                 RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
 
@@ -584,8 +1039,195 @@ impl Callable for StrFunction {
 
                 Value::String(new_string)
             }
+            StrFunction::Words => {
+                RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+                let string = arguments.first().unwrap().as_string()?;
+                let words = string
+                    .split_whitespace()
+                    .map(|word| Value::string(word.to_string()))
+                    .collect();
+
+                Value::List(List::with_items(words))
+            }
         };
 
         Ok(value)
     }
 }
+
+/// Calls `function` with the string of a builder created by `str:builder_new`.
+///
+/// A builder is a `StrBuilder` struct whose `buffer` is a list holding one
+/// string. Lists are shared between copies, so every copy of the builder sees
+/// the same string, and it is freed with the last copy.
+fn with_builder<T>(
+    builder: &Value,
+    function: impl FnOnce(&mut String) -> T,
+) -> Result<T, RuntimeError> {
+    if let Value::Struct(instance) = builder {
+        if instance.name() == &Identifier::new("StrBuilder") {
+            if let Some(Value::List(buffer)) = instance.map().get(&Identifier::new("buffer")) {
+                if let Some(Value::String(string)) = buffer.items_mut()?.first_mut() {
+                    return Ok(function(string));
+                }
+            }
+        }
+    }
+
+    Err(ValidationError::ExpectedStructInstance {
+        name: Identifier::new("StrBuilder"),
+        actual: builder.clone(),
+    }
+    .into())
+}
+
+/// Converts the result of a string search to the integer returned by the
+/// `index_of` functions.
+///
+/// The offset counts bytes, not characters, so slicing a string at an offset
+/// found with a needle that starts mid-character will not land on a character
+/// boundary.
+fn byte_offset(index: Option<usize>) -> Value {
+    Value::Integer(index.map(|index| index as i64).unwrap_or(-1))
+}
+
+/// Formats `arguments` according to the printf-style specifiers in `format`.
+///
+/// Each specifier has the form `%[flags][width][.precision]conversion`. The
+/// flags are `0` (pad numbers with zeros) and `-` (align left). The
+/// conversions are `d` (integer), `s` (any value), `f` and `e` (float), `x`
+/// and `o` (integer in hexadecimal or octal) and `%` for a literal percent
+/// sign.
+fn sprintf(function_name: &str, format: &str, arguments: &[Value]) -> Result<String, RuntimeError> {
+    let specifier_count = count_specifiers(format);
+
+    if specifier_count != arguments.len() {
+        return Err(RuntimeError::ExpectedBuiltInFunctionArgumentAmount {
+            function_name: function_name.to_string(),
+            expected: specifier_count + 1,
+            actual: arguments.len() + 1,
+        });
+    }
+
+    let mut output = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+    let mut arguments = arguments.iter();
+
+    while let Some(char) = chars.next() {
+        if char != '%' {
+            output.push(char);
+
+            continue;
+        }
+
+        if chars.next_if_eq(&'%').is_some() {
+            output.push('%');
+
+            continue;
+        }
+
+        let mut zero_pad = false;
+        let mut left_align = false;
+
+        while let Some(flag) = chars.next_if(|char| *char == '0' || *char == '-') {
+            if flag == '0' {
+                zero_pad = true;
+            } else {
+                left_align = true;
+            }
+        }
+
+        let width = parse_digits(&mut chars).unwrap_or(0);
+        let precision = if chars.next_if_eq(&'.').is_some() {
+            Some(parse_digits(&mut chars).unwrap_or(0))
+        } else {
+            None
+        };
+        let conversion = chars.next();
+        let argument = arguments.next().unwrap();
+
+        let body = match conversion {
+            Some('d') => argument.as_integer()?.to_string(),
+            Some('s') => {
+                let string = if let Value::String(string) = argument {
+                    string.clone()
+                } else {
+                    argument.to_string()
+                };
+
+                if let Some(precision) = precision {
+                    string.chars().take(precision).collect()
+                } else {
+                    string
+                }
+            }
+            Some('f') => format!("{:.*}", precision.unwrap_or(6), argument.as_float()?),
+            Some('e') => format!("{:.*e}", precision.unwrap_or(6), argument.as_float()?),
+            Some('x') => format!("{:x}", argument.as_integer()?),
+            Some('o') => format!("{:o}", argument.as_integer()?),
+            _ => {
+                return Err(RuntimeError::InvalidFormatSpecifier {
+                    specifier: format!("%{}", conversion.map(String::from).unwrap_or_default()),
+                })
+            }
+        };
+        let padding = width.saturating_sub(body.chars().count());
+
+        if left_align {
+            output.push_str(&body);
+            output.push_str(&" ".repeat(padding));
+        } else if zero_pad && conversion != Some('s') {
+            let (sign, digits) = if let Some(digits) = body.strip_prefix('-') {
+                ("-", digits)
+            } else {
+                ("", body.as_str())
+            };
+
+            output.push_str(sign);
+            output.push_str(&"0".repeat(padding));
+            output.push_str(digits);
+        } else {
+            output.push_str(&" ".repeat(padding));
+            output.push_str(&body);
+        }
+    }
+
+    Ok(output)
+}
+
+/// Counts the specifiers in a format string that consume an argument.
+fn count_specifiers(format: &str) -> usize {
+    let mut count = 0;
+    let mut chars = format.chars();
+
+    while let Some(char) = chars.next() {
+        if char == '%' && chars.next() != Some('%') {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn parse_digits(chars: &mut Peekable<Chars>) -> Option<usize> {
+    let mut number = None;
+
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        number = Some(number.unwrap_or(0) * 10 + digit.to_digit(10).unwrap() as usize);
+    }
+
+    number
+}
+
+/// Resolves a possibly negative index against a length, clamping it to the
+/// bounds instead of failing.
+pub(super) fn clamp_index(index: i64, length: usize) -> usize {
+    let index = if index < 0 {
+        length as i64 + index
+    } else {
+        index
+    };
+
+    index.clamp(0, length as i64) as usize
+}
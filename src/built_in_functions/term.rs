@@ -0,0 +1,128 @@
+use std::{
+    env,
+    io::{stdout, IsTerminal},
+};
+
+use colored::{control::SHOULD_COLORIZE, ColoredString, Colorize};
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, Type, Value};
+
+use super::Callable;
+
+pub fn term_functions() -> impl Iterator<Item = TermFunction> {
+    enum_iterator::all()
+}
+
+/// Terminal styling with ANSI escape sequences.
+///
+/// Styles are only applied when colors are enabled. Like the rest of the CLI,
+/// this follows the `colored` crate, which checks whether stdout is a terminal
+/// and respects `NO_COLOR` and `CLICOLOR_FORCE`. Colors are also disabled when
+/// `TERM` is `dumb`.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TermFunction {
+    Blue,
+    Bold,
+    Color,
+    Dim,
+    Green,
+    IsTty,
+    Red,
+}
+
+impl TermFunction {
+    /// Applies a style to the string argument if colors are enabled.
+    fn style(
+        &self,
+        arguments: &[Value],
+        style: impl FnOnce(&str) -> ColoredString,
+    ) -> Result<Value, RuntimeError> {
+        RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+        let string = arguments.first().unwrap().as_string()?;
+
+        if colors_enabled() {
+            Ok(Value::string(style(string).to_string()))
+        } else {
+            Ok(Value::string(string))
+        }
+    }
+}
+
+impl Callable for TermFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            TermFunction::Blue => "blue",
+            TermFunction::Bold => "bold",
+            TermFunction::Color => "color",
+            TermFunction::Dim => "dim",
+            TermFunction::Green => "green",
+            TermFunction::IsTty => "is_tty",
+            TermFunction::Red => "red",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            TermFunction::Blue => "Color a string blue.",
+            TermFunction::Bold => "Make a string bold.",
+            TermFunction::Color => "Color a string with red, green and blue values from 0 to 255.",
+            TermFunction::Dim => "Make a string dim.",
+            TermFunction::Green => "Color a string green.",
+            TermFunction::IsTty => "Check if standard output is a terminal.",
+            TermFunction::Red => "Color a string red.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            TermFunction::Color => Type::function(
+                vec![Type::String, Type::Integer, Type::Integer, Type::Integer],
+                Type::String,
+            ),
+            TermFunction::IsTty => Type::function(vec![], Type::Boolean),
+            _ => Type::function(vec![Type::String], Type::String),
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        match self {
+            TermFunction::Blue => self.style(arguments, |string| string.blue()),
+            TermFunction::Bold => self.style(arguments, |string| string.bold()),
+            TermFunction::Color => {
+                RuntimeError::expect_argument_amount(self.name(), 4, arguments.len())?;
+
+                let mut channels = [0; 3];
+
+                for (channel, argument) in channels.iter_mut().zip(&arguments[1..]) {
+                    *channel = argument.as_integer()?.clamp(0, 255) as u8;
+                }
+
+                let [red, green, blue] = channels;
+
+                self.style(&arguments[..1], |string| string.truecolor(red, green, blue))
+            }
+            TermFunction::Dim => self.style(arguments, |string| string.dimmed()),
+            TermFunction::Green => self.style(arguments, |string| string.green()),
+            TermFunction::IsTty => {
+                RuntimeError::expect_argument_amount(self.name(), 0, arguments.len())?;
+
+                Ok(Value::Boolean(stdout().is_terminal()))
+            }
+            TermFunction::Red => self.style(arguments, |string| string.red()),
+        }
+    }
+}
+
+fn colors_enabled() -> bool {
+    let is_dumb = env::var("TERM").is_ok_and(|term| term == "dumb");
+
+    !is_dumb && SHOULD_COLORIZE.should_colorize()
+}
@@ -0,0 +1,170 @@
+use std::str;
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::RuntimeError, Context, Type, Value};
+
+use super::Callable;
+
+pub fn url_functions() -> impl Iterator<Item = UrlFunction> {
+    enum_iterator::all()
+}
+
+/// Percent-encoding for URLs.
+///
+/// Only the unreserved characters `A-Z a-z 0-9 - _ . ~` are left as they are.
+/// `encode` also keeps `/` and `?` so that a whole path and query can be
+/// encoded at once, while `encode_component` encodes them too.
+#[derive(Sequence, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UrlFunction {
+    Decode,
+    DecodeLossy,
+    Encode,
+    EncodeComponent,
+}
+
+impl Callable for UrlFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            UrlFunction::Decode => "decode",
+            UrlFunction::DecodeLossy => "decode_lossy",
+            UrlFunction::Encode => "encode",
+            UrlFunction::EncodeComponent => "encode_component",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            UrlFunction::Decode => "Decode a percent-encoded string, or return an error.",
+            UrlFunction::DecodeLossy => {
+                "Decode a percent-encoded string, replacing invalid sequences with '?'."
+            }
+            UrlFunction::Encode => "Percent-encode a string, keeping '/' and '?'.",
+            UrlFunction::EncodeComponent => "Percent-encode a string, including '/' and '?'.",
+        }
+    }
+
+    fn r#type(&self) -> Type {
+        match self {
+            UrlFunction::Decode => {
+                Type::function(vec![Type::String], Type::result(Type::String, Type::String))
+            }
+            UrlFunction::DecodeLossy | UrlFunction::Encode | UrlFunction::EncodeComponent => {
+                Type::function(vec![Type::String], Type::String)
+            }
+        }
+    }
+
+    fn call(
+        &self,
+        arguments: &[Value],
+        _source: &str,
+        _context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        RuntimeError::expect_argument_amount(self.name(), 1, arguments.len())?;
+
+        let string = arguments.first().unwrap().as_string()?;
+        let value = match self {
+            UrlFunction::Decode => match decode(string, false) {
+                Ok(decoded) => Value::ok(Value::String(decoded)),
+                Err(message) => Value::error(Value::String(message)),
+            },
+            UrlFunction::DecodeLossy => {
+                // Lossy decoding replaces malformed input instead of failing.
+                Value::String(decode(string, true).unwrap_or_default())
+            }
+            UrlFunction::Encode => Value::String(encode(string, true)),
+            UrlFunction::EncodeComponent => Value::String(encode(string, false)),
+        };
+
+        Ok(value)
+    }
+}
+
+fn encode(string: &str, keep_separators: bool) -> String {
+    let mut encoded = String::with_capacity(string.len());
+
+    for byte in string.bytes() {
+        let is_unreserved = byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte);
+        let is_kept_separator = keep_separators && (byte == b'/' || byte == b'?');
+
+        if is_unreserved || is_kept_separator {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+fn decode(string: &str, lossy: bool) -> Result<String, String> {
+    let bytes = string.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        if byte != b'%' {
+            decoded.push(byte);
+            index += 1;
+
+            continue;
+        }
+
+        let high = bytes.get(index + 1).and_then(|digit| hex_value(*digit));
+        let low = bytes.get(index + 2).and_then(|digit| hex_value(*digit));
+
+        if let (Some(high), Some(low)) = (high, low) {
+            decoded.push(high * 16 + low);
+            index += 3;
+        } else if lossy {
+            decoded.push(b'?');
+            index += 1;
+
+            // Skip the rest of the malformed sequence without splitting a
+            // multi-byte character.
+            let sequence_end = (index + 2).min(bytes.len());
+
+            while index < sequence_end && bytes[index].is_ascii_alphanumeric() {
+                index += 1;
+            }
+        } else {
+            return Err(format!("Invalid percent-encoded sequence at byte {index}."));
+        }
+    }
+
+    if lossy {
+        Ok(replace_invalid_utf8(&decoded))
+    } else {
+        String::from_utf8(decoded).map_err(|_| "The decoded bytes are not valid UTF-8.".to_string())
+    }
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|value| value as u8)
+}
+
+fn replace_invalid_utf8(mut bytes: &[u8]) -> String {
+    let mut string = String::with_capacity(bytes.len());
+
+    loop {
+        match str::from_utf8(bytes) {
+            Ok(valid) => {
+                string.push_str(valid);
+
+                return string;
+            }
+            Err(error) => {
+                let (valid, rest) = bytes.split_at(error.valid_up_to());
+
+                string.push_str(str::from_utf8(valid).unwrap());
+                string.push('?');
+
+                bytes = &rest[error.error_len().unwrap_or(rest.len())..];
+            }
+        }
+    }
+}
@@ -4,16 +4,36 @@ use enum_iterator::{all, Sequence};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    built_in_functions::{fs::fs_functions, json::json_functions, str::string_functions, Callable},
+    built_in_functions::{
+        byte::byte_functions, cast::cast_functions, channel::channel_functions,
+        char::char_functions, date::date_functions, dir::dir_functions, float::float_functions,
+        fs::fs_functions, hash::hash_functions, int::int_functions, json::json_functions,
+        list::list_functions, map::map_functions, set::set_functions, str::string_functions,
+        term::term_functions, url::url_functions, Callable,
+    },
     BuiltInFunction, EnumInstance, Function, Identifier, List, Map, Value,
 };
 
 static ARGS: OnceLock<Value> = OnceLock::new();
+static BYTE: OnceLock<Value> = OnceLock::new();
+static CAST: OnceLock<Value> = OnceLock::new();
+static CHANNEL: OnceLock<Value> = OnceLock::new();
+static CHAR: OnceLock<Value> = OnceLock::new();
+static DATE: OnceLock<Value> = OnceLock::new();
+static DIR: OnceLock<Value> = OnceLock::new();
+static FLOAT: OnceLock<Value> = OnceLock::new();
 static FS: OnceLock<Value> = OnceLock::new();
+static HASH: OnceLock<Value> = OnceLock::new();
+static INT: OnceLock<Value> = OnceLock::new();
 static JSON: OnceLock<Value> = OnceLock::new();
+static LIST: OnceLock<Value> = OnceLock::new();
+static MAP: OnceLock<Value> = OnceLock::new();
 static NONE: OnceLock<Value> = OnceLock::new();
 static RANDOM: OnceLock<Value> = OnceLock::new();
+static SET: OnceLock<Value> = OnceLock::new();
 static STR: OnceLock<Value> = OnceLock::new();
+static TERM: OnceLock<Value> = OnceLock::new();
+static URL: OnceLock<Value> = OnceLock::new();
 
 /// Returns the entire built-in value API.
 pub fn all_built_in_values() -> impl Iterator<Item = BuiltInValue> {
@@ -26,29 +46,80 @@ pub enum BuiltInValue {
     /// The arguments used to launch the current program.
     Args,
 
+    /// Create an error if a condition is false.
+    Assert,
+
     /// Create an error if two values are not equal.
     AssertEqual,
 
+    /// ASCII byte tools.
+    Byte,
+
+    /// Runtime type conversions.
+    Cast,
+
+    /// Character tools.
+    Char,
+
+    /// Channels for passing values between threads.
+    Channel,
+
+    /// Date and time tools.
+    Date,
+
+    /// Directory tools.
+    Dir,
+
+    /// Float utilities.
+    Float,
+
     /// File system tools.
     Fs,
 
+    /// Non-cryptographic hash functions.
+    Hash,
+
+    /// Integer utilities.
+    Int,
+
     /// JSON format tools.
     Json,
 
     /// Get the length of a collection.
     Length,
 
+    /// List utilities.
+    List,
+
+    /// Map utilities.
+    Map,
+
     /// The absence of a value.
     None,
 
     /// Print a value to stdout.
     Output,
 
+    /// Stop the program with a message.
+    Panic,
+
+    /// Stop the program with a message if a condition is true.
+    PanicIf,
+
     /// Random value generators.
     Random,
 
+    /// Sets of unique values.
+    Set,
+
     /// String utilities.
     Str,
+
+    /// Terminal styling.
+    Term,
+
+    /// URL encoding tools.
+    Url,
 }
 
 impl BuiltInValue {
@@ -56,14 +127,31 @@ impl BuiltInValue {
     pub fn name(&self) -> &'static str {
         match self {
             BuiltInValue::Args => "args",
+            BuiltInValue::Assert => "assert",
             BuiltInValue::AssertEqual => "assert_equal",
+            BuiltInValue::Byte => "byte",
+            BuiltInValue::Cast => "cast",
+            BuiltInValue::Char => "char",
+            BuiltInValue::Channel => "channel",
+            BuiltInValue::Date => "date",
+            BuiltInValue::Dir => "dir",
+            BuiltInValue::Float => "float",
             BuiltInValue::Fs => "fs",
+            BuiltInValue::Hash => "hash",
+            BuiltInValue::Int => "int",
             BuiltInValue::Json => "json",
             BuiltInValue::Length => BuiltInFunction::Length.name(),
+            BuiltInValue::List => "list",
+            BuiltInValue::Map => "map",
             BuiltInValue::None => "None",
             BuiltInValue::Output => "output",
+            BuiltInValue::Panic => "panic",
+            BuiltInValue::PanicIf => "panic_if",
             BuiltInValue::Random => "random",
+            BuiltInValue::Set => "set",
             BuiltInValue::Str => "str",
+            BuiltInValue::Term => "term",
+            BuiltInValue::Url => "url",
         }
     }
 
@@ -73,14 +161,31 @@ impl BuiltInValue {
     pub fn description(&self) -> &'static str {
         match self {
             BuiltInValue::Args => "The command line arguments sent to this program.",
+            BuiltInValue::Assert => "Error if the condition is false.",
             BuiltInValue::AssertEqual => "Error if the two values are not equal.",
+            BuiltInValue::Byte => "ASCII tools for bytes.",
+            BuiltInValue::Cast => "Fallible type conversions.",
+            BuiltInValue::Char => "Tools for single characters.",
+            BuiltInValue::Channel => "Channels for passing values between threads.",
+            BuiltInValue::Date => "Date parsing and formatting.",
+            BuiltInValue::Dir => "Directory tools.",
+            BuiltInValue::Float => "Floating-point number tools.",
             BuiltInValue::Fs => "File and directory tools.",
+            BuiltInValue::Hash => "Fast, non-cryptographic hashing.",
+            BuiltInValue::Int => "Integer arithmetic tools.",
             BuiltInValue::Json => "JSON formatting tools.",
             BuiltInValue::Length => BuiltInFunction::Length.description(),
+            BuiltInValue::List => "List tools.",
+            BuiltInValue::Map => "Key-value map tools.",
             BuiltInValue::None => "The absence of a value.",
             BuiltInValue::Output => "output",
+            BuiltInValue::Panic => "Stop the program with a message.",
+            BuiltInValue::PanicIf => "Stop the program with a message if a condition is true.",
             BuiltInValue::Random => "random",
+            BuiltInValue::Set => "Sorted sets of unique values.",
             BuiltInValue::Str => "string",
+            BuiltInValue::Term => "Terminal colors and styles.",
+            BuiltInValue::Url => "Percent-encoding for URLs.",
         }
     }
 
@@ -95,9 +200,121 @@ impl BuiltInValue {
                     Value::List(List::with_items(args))
                 })
                 .clone(),
+            BuiltInValue::Assert => Value::Function(Function::BuiltIn(BuiltInFunction::Assert)),
             BuiltInValue::AssertEqual => {
                 Value::Function(Function::BuiltIn(BuiltInFunction::AssertEqual))
             }
+            BuiltInValue::Byte => BYTE
+                .get_or_init(|| {
+                    let mut byte_map = Map::new();
+
+                    for byte_function in byte_functions() {
+                        let key = byte_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::Byte(
+                            byte_function,
+                        )));
+
+                        byte_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(byte_map)
+                })
+                .clone(),
+            BuiltInValue::Cast => CAST
+                .get_or_init(|| {
+                    let mut cast_map = Map::new();
+
+                    for cast_function in cast_functions() {
+                        let key = cast_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::Cast(
+                            cast_function,
+                        )));
+
+                        cast_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(cast_map)
+                })
+                .clone(),
+            BuiltInValue::Char => CHAR
+                .get_or_init(|| {
+                    let mut char_map = Map::new();
+
+                    for char_function in char_functions() {
+                        let key = char_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::Char(
+                            char_function,
+                        )));
+
+                        char_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(char_map)
+                })
+                .clone(),
+            BuiltInValue::Channel => CHANNEL
+                .get_or_init(|| {
+                    let mut channel_map = Map::new();
+
+                    for channel_function in channel_functions() {
+                        let key = channel_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::Channel(
+                            channel_function,
+                        )));
+
+                        channel_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(channel_map)
+                })
+                .clone(),
+            BuiltInValue::Date => DATE
+                .get_or_init(|| {
+                    let mut date_map = Map::new();
+
+                    for date_function in date_functions() {
+                        let key = date_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::Date(
+                            date_function,
+                        )));
+
+                        date_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(date_map)
+                })
+                .clone(),
+            BuiltInValue::Dir => DIR
+                .get_or_init(|| {
+                    let mut dir_map = Map::new();
+
+                    for dir_function in dir_functions() {
+                        let key = dir_function.name();
+                        let value =
+                            Value::Function(Function::BuiltIn(BuiltInFunction::Dir(dir_function)));
+
+                        dir_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(dir_map)
+                })
+                .clone(),
+            BuiltInValue::Float => FLOAT
+                .get_or_init(|| {
+                    let mut float_map = Map::new();
+
+                    for float_function in float_functions() {
+                        let key = float_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::Float(
+                            float_function,
+                        )));
+
+                        float_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(float_map)
+                })
+                .clone(),
             BuiltInValue::Fs => FS
                 .get_or_init(|| {
                     let mut fs_map = Map::new();
@@ -113,6 +330,37 @@ impl BuiltInValue {
                     Value::Map(fs_map)
                 })
                 .clone(),
+            BuiltInValue::Hash => HASH
+                .get_or_init(|| {
+                    let mut hash_map = Map::new();
+
+                    for hash_function in hash_functions() {
+                        let key = hash_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::Hash(
+                            hash_function,
+                        )));
+
+                        hash_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(hash_map)
+                })
+                .clone(),
+            BuiltInValue::Int => INT
+                .get_or_init(|| {
+                    let mut int_map = Map::new();
+
+                    for int_function in int_functions() {
+                        let key = int_function.name();
+                        let value =
+                            Value::Function(Function::BuiltIn(BuiltInFunction::Int(int_function)));
+
+                        int_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(int_map)
+                })
+                .clone(),
             BuiltInValue::Json => JSON
                 .get_or_init(|| {
                     let mut json_map = Map::new();
@@ -130,6 +378,37 @@ impl BuiltInValue {
                 })
                 .clone(),
             BuiltInValue::Length => Value::Function(Function::BuiltIn(BuiltInFunction::Length)),
+            BuiltInValue::List => LIST
+                .get_or_init(|| {
+                    let mut list_map = Map::new();
+
+                    for list_function in list_functions() {
+                        let key = list_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::List(
+                            list_function,
+                        )));
+
+                        list_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(list_map)
+                })
+                .clone(),
+            BuiltInValue::Map => MAP
+                .get_or_init(|| {
+                    let mut map = Map::new();
+
+                    for map_function in map_functions() {
+                        let key = map_function.name();
+                        let value =
+                            Value::Function(Function::BuiltIn(BuiltInFunction::Map(map_function)));
+
+                        map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(map)
+                })
+                .clone(),
             BuiltInValue::None => NONE
                 .get_or_init(|| {
                     Value::Enum(EnumInstance::new(
@@ -140,6 +419,8 @@ impl BuiltInValue {
                 })
                 .clone(),
             BuiltInValue::Output => Value::Function(Function::BuiltIn(BuiltInFunction::Output)),
+            BuiltInValue::Panic => Value::Function(Function::BuiltIn(BuiltInFunction::Panic)),
+            BuiltInValue::PanicIf => Value::Function(Function::BuiltIn(BuiltInFunction::PanicIf)),
             BuiltInValue::Random => RANDOM
                 .get_or_init(|| {
                     let mut random_map = Map::new();
@@ -159,6 +440,21 @@ impl BuiltInValue {
                     Value::Map(random_map)
                 })
                 .clone(),
+            BuiltInValue::Set => SET
+                .get_or_init(|| {
+                    let mut set_map = Map::new();
+
+                    for set_function in set_functions() {
+                        let key = set_function.name();
+                        let value =
+                            Value::Function(Function::BuiltIn(BuiltInFunction::Set(set_function)));
+
+                        set_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(set_map)
+                })
+                .clone(),
             BuiltInValue::Str => STR
                 .get_or_init(|| {
                     let mut str_map = Map::new();
@@ -175,6 +471,37 @@ impl BuiltInValue {
                     Value::Map(str_map)
                 })
                 .clone(),
+            BuiltInValue::Term => TERM
+                .get_or_init(|| {
+                    let mut term_map = Map::new();
+
+                    for term_function in term_functions() {
+                        let key = term_function.name();
+                        let value = Value::Function(Function::BuiltIn(BuiltInFunction::Term(
+                            term_function,
+                        )));
+
+                        term_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(term_map)
+                })
+                .clone(),
+            BuiltInValue::Url => URL
+                .get_or_init(|| {
+                    let mut url_map = Map::new();
+
+                    for url_function in url_functions() {
+                        let key = url_function.name();
+                        let value =
+                            Value::Function(Function::BuiltIn(BuiltInFunction::Url(url_function)));
+
+                        url_map.set(Identifier::new(key), value);
+                    }
+
+                    Value::Map(url_map)
+                })
+                .clone(),
         }
     }
 }
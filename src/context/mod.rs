@@ -29,6 +29,23 @@
 //! in values and type definitions for a match. This means that the user can
 //! override the built-ins.
 //!
+//! ## File system access
+//!
+//! A new Context is sandboxed: the `fs` functions may only read paths inside
+//! the working directory and may not change any files. Use
+//! [Context::set_allow_io][] to lift these limits.
+//!
+//! ```
+//! # use dust_lang::*;
+//! let context = Context::default();
+//!
+//! assert!(interpret_with_context("fs:exists('/tmp')", context.clone()).is_err());
+//!
+//! context.set_allow_io(true);
+//!
+//! assert!(interpret_with_context("fs:exists('/tmp')", context).is_ok());
+//! ```
+//!
 //! ## Garbage Collection
 //!
 //! To disable garbage collection, run a Context in AllowGarbage mode.
@@ -55,7 +72,10 @@ use std::{
     cmp::Ordering,
     collections::BTreeMap,
     fmt::Display,
-    sync::{Arc, RwLock, RwLockReadGuard},
+    sync::{
+        atomic::{AtomicBool, Ordering as AtomicOrdering},
+        Arc, RwLock, RwLockReadGuard,
+    },
 };
 
 use crate::{
@@ -76,6 +96,7 @@ pub enum ContextMode {
 #[derive(Clone, Debug)]
 pub struct Context {
     mode: ContextMode,
+    allow_io: Arc<AtomicBool>,
     inner: Arc<RwLock<BTreeMap<Identifier, (ValueData, UsageCounter)>>>,
 }
 
@@ -84,6 +105,7 @@ impl Context {
     pub fn new(mode: ContextMode) -> Self {
         Self {
             mode,
+            allow_io: Arc::new(AtomicBool::new(false)),
             inner: Arc::new(RwLock::new(BTreeMap::new())),
         }
     }
@@ -108,6 +130,7 @@ impl Context {
 
         Ok(Context {
             mode: other.mode.clone(),
+            allow_io: Arc::new(AtomicBool::new(other.allows_io())),
             inner: Arc::new(RwLock::new(new_variables)),
         })
     }
@@ -115,8 +138,10 @@ impl Context {
     /// Modify a context to take the functions and type definitions of another.
     ///
     /// In the case of the conflict, the inherited value will override the previous
-    /// value.
+    /// value. The other context's permission to use IO is also taken.
     pub fn inherit_from(&self, other: &Context) -> Result<(), RwLockError> {
+        self.set_allow_io(other.allows_io());
+
         let mut self_variables = self.inner.write()?;
 
         for (identifier, (value_data, counter)) in other.inner.read()?.iter() {
@@ -142,7 +167,7 @@ impl Context {
     /// Modify a context to take all the information of another.
     ///
     /// In the case of the conflict, the inherited value will override the previous
-    /// value.
+    /// value. The other context's permission to use IO is also taken.
     ///
     /// ```
     /// # use dust_lang::*;
@@ -159,6 +184,8 @@ impl Context {
     /// assert_eq!(first_context, second_context);
     /// ```
     pub fn inherit_all_from(&self, other: &Context) -> Result<(), RwLockError> {
+        self.set_allow_io(other.allows_io());
+
         let mut self_variables = self.inner.write()?;
 
         for (identifier, (value_data, _counter)) in other.inner.read()?.iter() {
@@ -171,6 +198,18 @@ impl Context {
         Ok(())
     }
 
+    /// Check if the `fs` functions may use any path and change files.
+    ///
+    /// See the [module-level docs][self] for more info.
+    pub fn allows_io(&self) -> bool {
+        self.allow_io.load(AtomicOrdering::Relaxed)
+    }
+
+    /// Allow or forbid the `fs` functions to use any path and change files.
+    pub fn set_allow_io(&self, allow_io: bool) {
+        self.allow_io.store(allow_io, AtomicOrdering::Relaxed);
+    }
+
     /// Increment the number of allowances a variable has. Return a boolean
     /// representing whether or not the variable was found.
     pub fn add_allowance(&self, identifier: &Identifier) -> Result<bool, RwLockError> {
@@ -313,6 +352,15 @@ impl Context {
 
         Ok(())
     }
+
+    /// Remove every key-value pair.
+    pub fn clear(&self) -> Result<(), RwLockError> {
+        log::debug!("Dropping all variables.");
+
+        self.inner.write()?.clear();
+
+        Ok(())
+    }
 }
 
 impl Default for Context {
@@ -21,9 +21,27 @@ pub enum RuntimeError {
         right: Value,
     },
 
-    /// The 'assert' macro did not resolve successfully.
+    /// The condition passed to `assert` was false.
+    ///
+    /// The position is filled in by the function call that failed.
     AssertFailed {
-        assertion: Value,
+        message: String,
+        position: Option<SourcePosition>,
+    },
+
+    /// A value with no JSON representation, such as a function or an enum
+    /// other than `Option::None`, was converted to JSON.
+    CannotSerialize {
+        value: Value,
+    },
+
+    /// Not an error: carries the value of a `break` statement out of nested
+    /// blocks to the loop that it ends.
+    ///
+    /// A `break` outside of a loop is a [SyntaxError][super::SyntaxError], so
+    /// the loop around it always catches this.
+    Break {
+        value: Value,
     },
 
     /// The attempted conversion is impossible.
@@ -43,6 +61,9 @@ pub enum RuntimeError {
 
     SystemTime(String),
 
+    /// A date or time was out of the supported range.
+    Time(String),
+
     Toml(toml::de::Error),
 
     /// Failed to read or write a map.
@@ -61,6 +82,59 @@ pub enum RuntimeError {
         actual: usize,
     },
 
+    /// A built-in function with optional arguments was called with too few or
+    /// too many arguments.
+    ExpectedBuiltInFunctionArgumentRange {
+        function_name: String,
+        minimum: usize,
+        maximum: usize,
+        actual: usize,
+    },
+
+    /// A built-in function that takes arguments in pairs was called with an
+    /// odd number of arguments.
+    ExpectedEvenArgumentAmount {
+        function_name: String,
+        actual: usize,
+    },
+
+    /// A format string contained a conversion that is not supported.
+    InvalidFormatSpecifier {
+        specifier: String,
+    },
+
+    /// A number was formatted with a radix outside of 2 to 36.
+    InvalidRadix {
+        radix: i64,
+    },
+
+    /// An integer's bits were accessed at a position outside of 0 to 63.
+    InvalidBitPosition {
+        position: i64,
+    },
+
+    /// A string was sliced at a byte offset inside a multi-byte character.
+    InvalidCharBoundary {
+        index: usize,
+    },
+
+    /// An `fs` function was given a path that it may not use because the
+    /// context does not allow IO.
+    ///
+    /// See [Context::allows_io][crate::Context::allows_io] for more info.
+    IoNotAllowed {
+        function_name: String,
+        path: String,
+    },
+
+    /// The program called `panic` or `panic_if`.
+    ///
+    /// The position is filled in by the function call that panicked.
+    Panic {
+        message: String,
+        position: Option<SourcePosition>,
+    },
+
     ValidationFailure(ValidationError),
 }
 
@@ -77,7 +151,23 @@ impl RuntimeError {
                     (200, 0, 0),
                 )]
             }
-            RuntimeError::AssertFailed { assertion: _ } => todo!(),
+            RuntimeError::AssertFailed { message, position } => {
+                let span = position
+                    .map(|position| position.start_byte..position.end_byte)
+                    .unwrap_or(0..source.len());
+
+                vec![(span, format!("Assertion failed: {message}"), (200, 0, 0))]
+            }
+            RuntimeError::Break { .. } => vec![(
+                0..source.len(),
+                "A break was used outside of a loop.".to_string(),
+                (200, 0, 0),
+            )],
+            RuntimeError::CannotSerialize { value } => vec![(
+                0..source.len(),
+                format!("{value} cannot be converted to JSON."),
+                (200, 0, 0),
+            )],
             RuntimeError::ConversionImpossible { from, to, position } => vec![(
                 position.start_byte..position.end_byte,
                 format!("Cannot convert from {from} to {to}."),
@@ -88,6 +178,7 @@ impl RuntimeError {
             RuntimeError::Reqwest(_) => todo!(),
             RuntimeError::Json(_) => todo!(),
             RuntimeError::SystemTime(_) => todo!(),
+            RuntimeError::Time(message) => vec![(0..source.len(), message.clone(), (200, 0, 0))],
             RuntimeError::Toml(_) => todo!(),
             RuntimeError::RwLock(_) => todo!(),
             RuntimeError::ParseFloat(_) => todo!(),
@@ -97,7 +188,68 @@ impl RuntimeError {
                 expected: _,
                 actual: _,
             } => todo!(),
-            RuntimeError::ValidationFailure(_) => todo!(),
+            RuntimeError::ExpectedBuiltInFunctionArgumentRange {
+                function_name,
+                minimum,
+                maximum,
+                actual,
+            } => vec![(
+                0..source.len(),
+                format!(
+                    "\"{function_name}\" expected {minimum} to {maximum} arguments but got {actual}."
+                ),
+                (200, 0, 0),
+            )],
+            RuntimeError::ExpectedEvenArgumentAmount {
+                function_name,
+                actual,
+            } => vec![(
+                0..source.len(),
+                format!(
+                    "\"{function_name}\" expected an even number of arguments but got {actual}."
+                ),
+                (200, 0, 0),
+            )],
+            RuntimeError::InvalidFormatSpecifier { specifier } => vec![(
+                0..source.len(),
+                format!("\"{specifier}\" is not a valid format specifier."),
+                (200, 0, 0),
+            )],
+            RuntimeError::InvalidRadix { radix } => vec![(
+                0..source.len(),
+                format!("{radix} is not a valid radix. Use a number from 2 to 36."),
+                (200, 0, 0),
+            )],
+            RuntimeError::InvalidBitPosition { position } => vec![(
+                0..source.len(),
+                format!("{position} is not a valid bit position. Use a number from 0 to 63."),
+                (200, 0, 0),
+            )],
+            RuntimeError::InvalidCharBoundary { index } => vec![(
+                0..source.len(),
+                format!("Byte offset {index} is inside of a multi-byte character."),
+                (200, 0, 0),
+            )],
+            RuntimeError::IoNotAllowed {
+                function_name,
+                path,
+            } => vec![(
+                0..source.len(),
+                format!(
+                    "\"{function_name}\" cannot use \"{path}\" without permission to use IO. Run dust with --allow-io to give it permission."
+                ),
+                (200, 0, 0),
+            )],
+            RuntimeError::Panic { message, position } => {
+                let span = position
+                    .map(|position| position.start_byte..position.end_byte)
+                    .unwrap_or(0..source.len());
+
+                vec![(span, format!("Panicked: {message}"), (200, 0, 0))]
+            }
+            RuntimeError::ValidationFailure(validation_error) => {
+                return validation_error.create_report(source)
+            }
         };
 
         Report::new_byte_spanned(source, messages).display_str()
@@ -118,6 +270,26 @@ impl RuntimeError {
             })
         }
     }
+
+    /// Like [Self::expect_argument_amount], for functions whose last
+    /// arguments are optional.
+    pub fn expect_argument_range(
+        function_name: &str,
+        minimum: usize,
+        maximum: usize,
+        actual: usize,
+    ) -> Result<(), Self> {
+        if (minimum..=maximum).contains(&actual) {
+            Ok(())
+        } else {
+            Err(RuntimeError::ExpectedBuiltInFunctionArgumentRange {
+                function_name: function_name.to_string(),
+                minimum,
+                maximum,
+                actual,
+            })
+        }
+    }
 }
 
 impl From<ValidationError> for RuntimeError {
@@ -156,6 +328,12 @@ impl From<time::SystemTimeError> for RuntimeError {
     }
 }
 
+impl From<::time::error::ComponentRange> for RuntimeError {
+    fn from(error: ::time::error::ComponentRange) -> Self {
+        RuntimeError::Time(error.to_string())
+    }
+}
+
 impl From<toml::de::Error> for RuntimeError {
     fn from(error: toml::de::Error) -> Self {
         RuntimeError::Toml(error)
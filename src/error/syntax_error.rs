@@ -11,6 +11,12 @@ use super::rw_lock_error::RwLockError;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum SyntaxError {
+    /// A `break` that is not inside a loop, including one in a function body
+    /// that is itself inside a loop.
+    BreakOutsideLoop {
+        position: SourcePosition,
+    },
+
     /// Invalid user input.
     InvalidSource {
         expected: String,
@@ -18,6 +24,12 @@ pub enum SyntaxError {
         position: SourcePosition,
     },
 
+    /// A backslash in a string literal that does not start a known escape.
+    InvalidEscapeSequence {
+        sequence: String,
+        position: SourcePosition,
+    },
+
     RwLock(RwLockError),
 
     UnexpectedSyntaxNode {
@@ -30,6 +42,11 @@ pub enum SyntaxError {
 impl SyntaxError {
     pub fn create_report(&self, source: &str) -> String {
         let messages = match self {
+            SyntaxError::BreakOutsideLoop { position } => vec![(
+                position.start_byte..position.end_byte,
+                self.to_string(),
+                (255, 200, 100),
+            )],
             SyntaxError::InvalidSource { position, .. } => self
                 .to_string()
                 .split_inclusive(".")
@@ -41,6 +58,13 @@ impl SyntaxError {
                     )
                 })
                 .collect(),
+            SyntaxError::InvalidEscapeSequence { position, .. } => {
+                vec![(
+                    position.start_byte..position.end_byte,
+                    self.to_string(),
+                    (255, 200, 100),
+                )]
+            }
             SyntaxError::RwLock(_) => todo!(),
             SyntaxError::UnexpectedSyntaxNode { position, .. } => {
                 vec![(
@@ -57,7 +81,15 @@ impl SyntaxError {
     pub fn expect_syntax_node(expected: &str, actual: SyntaxNode) -> Result<(), SyntaxError> {
         log::trace!("Converting {} to abstract node", actual.kind());
 
-        if expected == actual.kind() {
+        if actual.is_missing() {
+            // Tree-sitter inserts an empty node where one was required but
+            // absent, such as the value after a bare `return`.
+            Err(SyntaxError::InvalidSource {
+                expected: expected.to_owned(),
+                actual: "nothing".to_string(),
+                position: SourcePosition::from(actual.range()),
+            })
+        } else if expected == actual.kind() {
             Ok(())
         } else if actual.is_error() {
             Err(SyntaxError::InvalidSource {
@@ -84,6 +116,11 @@ impl From<RwLockError> for SyntaxError {
 impl Display for SyntaxError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
+            SyntaxError::BreakOutsideLoop { position } => write!(
+                f,
+                "A break at ({}, {}) is not inside a loop.",
+                position.start_row, position.start_column,
+            ),
             SyntaxError::InvalidSource {
                 expected,
                 actual,
@@ -106,6 +143,13 @@ impl Display for SyntaxError {
                     actual.bold().red(),
                 )
             }
+            SyntaxError::InvalidEscapeSequence { sequence, position } => write!(
+                f,
+                "Invalid escape sequence {} at ({}, {}).",
+                sequence.bold().red(),
+                position.start_row,
+                position.start_column,
+            ),
             SyntaxError::RwLock(_) => todo!(),
             SyntaxError::UnexpectedSyntaxNode {
                 expected,
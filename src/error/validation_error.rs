@@ -48,6 +48,11 @@ pub enum ValidationError {
         actual: Value,
     },
 
+    /// A string containing exactly one character was expected.
+    ExpectedCharacter {
+        actual: Value,
+    },
+
     ExpectedInteger {
         actual: Value,
     },
@@ -88,6 +93,16 @@ pub enum ValidationError {
         actual: Value,
     },
 
+    ExpectedSet {
+        actual: Value,
+    },
+
+    /// An instance of the named struct was expected.
+    ExpectedStructInstance {
+        name: Identifier,
+        actual: Value,
+    },
+
     ExpectedFunction {
         actual: Value,
     },
@@ -118,6 +133,12 @@ pub enum ValidationError {
         position: SourcePosition,
     },
 
+    /// A match statement does not cover every possible value of its matcher.
+    NonExhaustiveMatch {
+        missing: Vec<String>,
+        position: SourcePosition,
+    },
+
     /// Failed to read or write a map.
     ///
     /// See the [MapError] docs for more info.
@@ -185,6 +206,14 @@ impl ValidationError {
                 target_type: _,
             } => todo!(),
             ValidationError::ExpectedString { actual: _ } => todo!(),
+            ValidationError::ExpectedCharacter { actual } => vec![(
+                0..source.len(),
+                format!(
+                    "Expected a single character but got {}.",
+                    actual.to_string().bold().red()
+                ),
+                (200, 200, 200),
+            )],
             ValidationError::ExpectedInteger { actual: _ } => todo!(),
             ValidationError::ExpectedFloat { actual: _ } => todo!(),
             ValidationError::ExpectedNumber { actual: _ } => todo!(),
@@ -200,6 +229,23 @@ impl ValidationError {
                 actual: _,
             } => todo!(),
             ValidationError::ExpectedMap { actual: _ } => todo!(),
+            ValidationError::ExpectedSet { actual } => vec![(
+                0..source.len(),
+                format!(
+                    "Expected a set but got {}.",
+                    actual.to_string().bold().red()
+                ),
+                (200, 200, 200),
+            )],
+            ValidationError::ExpectedStructInstance { name, actual } => vec![(
+                0..source.len(),
+                format!(
+                    "Expected an instance of {} but got {}.",
+                    name.to_string().bold().green(),
+                    actual.to_string().bold().red()
+                ),
+                (200, 200, 200),
+            )],
             ValidationError::ExpectedFunction { actual: _ } => todo!(),
             ValidationError::ExpectedCollection { actual: _ } => todo!(),
             ValidationError::ExpectedBuiltInFunctionArgumentAmount {
@@ -217,6 +263,14 @@ impl ValidationError {
                 actual: _,
                 position: _,
             } => todo!(),
+            ValidationError::NonExhaustiveMatch { missing, position } => vec![(
+                position.start_byte..position.end_byte,
+                format!(
+                    "This match does not cover {}.",
+                    missing.join(" or ").bold().red()
+                ),
+                (200, 200, 200),
+            )],
             ValidationError::RwLock(_) => todo!(),
             ValidationError::TypeCheck {
                 expected,
@@ -1,9 +1,10 @@
 //! Tools to interpret dust source code.
 //!
-//! This module has three tools to run Dust code.
+//! This module has four tools to run or check Dust code.
 //!
 //! - [interpret] is the simplest way to run Dust code inside of an application or library
 //! - [interpret_with_context] allows you to set variables on the execution context
+//! - [check] finds syntax and type errors without running anything
 //! - [Interpreter] is an advanced tool that can parse, validate, run and format Dust code
 //!
 //! # Examples
@@ -57,6 +58,21 @@ pub fn interpret_with_context(source: &str, context: Context) -> Result<Value, E
     Ok(value)
 }
 
+/// Parse and validate the source without running it.
+///
+/// ```
+/// # use dust_lang::*;
+/// assert!(check("x = 1 + 2").is_ok());
+/// assert!(check("x <str> = 1").is_err());
+/// ```
+pub fn check(source: &str) -> Result<(), Error> {
+    let mut interpreter = Interpreter::new(Context::new(ContextMode::RemoveGarbage));
+
+    interpreter.validate(source)?;
+
+    Ok(())
+}
+
 /// A source code interpreter for the Dust language.
 ///
 /// The interpreter's most important functions are used to parse dust source
@@ -7,12 +7,21 @@ use nu_ansi_term::{Color, Style};
 use reedline::{
     default_emacs_keybindings, ColumnarMenu, Completer, DefaultHinter, EditCommand, Emacs, Prompt,
     Reedline, ReedlineEvent, ReedlineMenu, Signal, Span, SqliteBackedHistory, Suggestion,
+    ValidationResult, Validator,
 };
 
-use std::{borrow::Cow, fs::read_to_string, io::Write, path::PathBuf, process::Command};
+use std::{
+    borrow::Cow,
+    fs::{metadata, read_to_string},
+    io::Write,
+    path::PathBuf,
+    process::{exit, Command},
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
 
 use dust_lang::{
-    built_in_values::all_built_in_values, Context, ContextMode, Error, Interpreter, Value,
+    built_in_values::all_built_in_values, check, Context, ContextMode, Error, Interpreter, Value,
     ValueData,
 };
 
@@ -28,12 +37,32 @@ struct Args {
     #[command(subcommand)]
     cli_command: Option<CliCommand>,
 
+    /// Run the file again whenever it changes.
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Milliseconds that a changed file must stay unchanged before it runs.
+    #[arg(long, default_value_t = 100)]
+    debounce_ms: u64,
+
+    /// Run the watched file once and exit instead of waiting for changes.
+    #[arg(long, requires = "watch")]
+    run_once: bool,
+
+    /// Let the program read and change files anywhere. Without this, it can
+    /// only read files inside the working directory.
+    #[arg(long)]
+    allow_io: bool,
+
     /// Location of the file to run.
     path: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
 pub enum CliCommand {
+    /// Check a file for syntax and type errors without running it.
+    Check { path: String },
+
     /// Output a formatted version of the input.
     Format,
 
@@ -55,6 +84,21 @@ fn main() {
     let args = Args::parse();
     let context = Context::new(ContextMode::AllowGarbage);
 
+    context.set_allow_io(args.allow_io);
+
+    if let Some(CliCommand::Check { path }) = &args.cli_command {
+        let source = read_to_string(path).unwrap();
+
+        if let Err(error) = check(&source) {
+            eprintln!("{}", error.create_report(&source));
+            exit(1);
+        }
+
+        println!("No errors found in {path}.");
+
+        return;
+    }
+
     if args.path.is_none() && args.command.is_none() {
         let run_shell_result = run_shell(context);
 
@@ -66,6 +110,21 @@ fn main() {
         return;
     }
 
+    if args.watch {
+        if let Some(path) = &args.path {
+            watch(
+                path,
+                Duration::from_millis(args.debounce_ms),
+                args.run_once,
+                args.allow_io,
+            );
+        } else {
+            eprintln!("The --watch flag requires a file path.");
+        }
+
+        return;
+    }
+
     let source = if let Some(path) = &args.path {
         read_to_string(path).unwrap()
     } else if let Some(command) = args.command {
@@ -105,6 +164,65 @@ fn main() {
     }
 }
 
+/// Run the file at `path`, then run it again each time it is modified.
+///
+/// Each run starts with an empty context, which allows IO if `allow_io` is
+/// set. Errors are printed and watching
+/// continues. A change only starts a run once the file has gone a full
+/// `debounce` without changing again, so a burst of writes runs it once. With
+/// `run_once`, the file runs a single time and watching stops.
+fn watch(path: &str, debounce: Duration, run_once: bool, allow_io: bool) {
+    let modified = || metadata(path).and_then(|metadata| metadata.modified()).ok();
+
+    loop {
+        let last_modified: Option<SystemTime> = modified();
+
+        match read_to_string(path) {
+            Ok(source) => {
+                let context = Context::new(ContextMode::AllowGarbage);
+
+                context.set_allow_io(allow_io);
+
+                let mut interpreter = Interpreter::new(context);
+
+                match interpreter.run(&source) {
+                    Ok(value) => {
+                        if !value.is_none() {
+                            println!("{value}")
+                        }
+                    }
+                    Err(error) => eprintln!("{}", error.create_report(&source)),
+                }
+            }
+            Err(error) => eprintln!("{error}"),
+        }
+
+        if run_once {
+            return;
+        }
+
+        println!("{}", format!("Watching {path} for changes.").dimmed());
+
+        while modified() == last_modified {
+            sleep(debounce);
+        }
+
+        let mut settled = modified();
+
+        loop {
+            sleep(debounce);
+
+            let current = modified();
+
+            if current == settled {
+                break;
+            }
+
+            settled = current;
+        }
+    }
+}
+
 // struct DustHighlighter {
 //     context: Context,
 // }
@@ -207,7 +325,7 @@ impl Prompt for StarshipPrompt {
     }
 
     fn render_prompt_multiline_indicator(&self) -> Cow<str> {
-        Cow::Borrowed("")
+        Cow::Borrowed("... ")
     }
 
     fn render_prompt_history_search_indicator(
@@ -318,6 +436,53 @@ impl Completer for DustCompleter {
     }
 }
 
+/// Keeps the shell reading lines while a block, list, group or string is
+/// unclosed.
+///
+/// Comments are skipped up to the next `#` or line break, and a backslash in a
+/// string escapes the character after it.
+pub struct DustValidator;
+
+impl Validator for DustValidator {
+    fn validate(&self, line: &str) -> ValidationResult {
+        let mut depth = 0;
+        let mut quote = None;
+        let mut chars = line.chars();
+
+        while let Some(char) = chars.next() {
+            match (quote, char) {
+                (Some(_), '\\') => {
+                    chars.next();
+                }
+                (Some(open), _) if char == open => quote = None,
+                (Some(_), _) => {}
+                (None, '#') => {
+                    for comment_char in chars.by_ref() {
+                        if comment_char == '#' || comment_char == '\n' {
+                            break;
+                        }
+                    }
+                }
+                (None, '\'' | '"' | '`') => quote = Some(char),
+                (None, '{' | '[' | '(') => depth += 1,
+                (None, '}' | ']' | ')') => depth -= 1,
+                _ => {}
+            }
+        }
+
+        if depth > 0 || quote.is_some() {
+            ValidationResult::Incomplete
+        } else {
+            ValidationResult::Complete
+        }
+    }
+}
+
+const SHELL_HELP: &str = "\
+:help        Show this message.
+:clear       Remove every variable.
+:q, :quit    Leave the shell.";
+
 fn run_shell(context: Context) -> Result<(), Error> {
     let mut interpreter = Interpreter::new(context.clone());
     let mut keybindings = default_emacs_keybindings();
@@ -360,6 +525,7 @@ fn run_shell(context: Context) -> Result<(), Error> {
         .with_hinter(hinter)
         .use_kitty_keyboard_enhancement(true)
         .with_completer(Box::new(completer))
+        .with_validator(Box::new(DustValidator))
         .with_menu(ReedlineMenu::EngineCompleter(Box::new(
             ColumnarMenu::default()
                 .with_name("context menu")
@@ -376,8 +542,24 @@ fn run_shell(context: Context) -> Result<(), Error> {
 
         match sig {
             Ok(Signal::Success(buffer)) => {
-                if buffer.trim().is_empty() {
-                    continue;
+                match buffer.trim() {
+                    "" => continue,
+                    ":q" | ":quit" => {
+                        println!("Leaving the Dust shell.");
+                        break;
+                    }
+                    ":help" => {
+                        println!("{SHELL_HELP}");
+                        continue;
+                    }
+                    ":clear" => {
+                        if let Err(error) = context.clear() {
+                            eprintln!("{error}");
+                        }
+
+                        continue;
+                    }
+                    _ => {}
                 }
 
                 let run_result = interpreter.run(&buffer);
@@ -405,3 +587,38 @@ fn run_shell(context: Context) -> Result<(), Error> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use reedline::{ValidationResult, Validator};
+
+    use super::DustValidator;
+
+    fn is_complete(line: &str) -> bool {
+        matches!(DustValidator.validate(line), ValidationResult::Complete)
+    }
+
+    #[test]
+    fn unclosed_delimiters_are_incomplete() {
+        assert!(!is_complete("x = {"));
+        assert!(!is_complete("x = [1 2"));
+        assert!(!is_complete("x = 'abc"));
+        assert!(is_complete("x = { y = [1 2] }"));
+    }
+
+    #[test]
+    fn comments_are_skipped() {
+        assert!(is_complete("# don't"));
+        assert!(is_complete("x = 1 # a { comment"));
+        assert!(is_complete("x = 1 # don't # y = 2"));
+        assert!(!is_complete("# note # x = {"));
+        assert!(is_complete("x = '# not a comment'"));
+    }
+
+    #[test]
+    fn escaped_quotes_do_not_close_strings() {
+        assert!(is_complete("x = 'it\\'s'"));
+        assert!(is_complete("x = \"say \\\"hi\\\"\""));
+        assert!(!is_complete("x = 'it\\'s"));
+    }
+}
@@ -3,7 +3,8 @@ use std::fmt::{self, Display, Formatter};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    built_in_functions::Callable, BuiltInFunction, Format, FunctionNode, Identifier, Type,
+    built_in_functions::Callable, error::RuntimeError, AbstractTree, BuiltInFunction, Context,
+    Format, FunctionNode, Identifier, Type, Value,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -22,6 +23,37 @@ impl Function {
         }
     }
 
+    /// Calls the function with arguments that have already been evaluated.
+    ///
+    /// Context-defined functions run in a new context that inherits from the
+    /// one they were defined in and from `context`.
+    pub fn call(
+        &self,
+        arguments: &[Value],
+        source: &str,
+        context: &Context,
+    ) -> Result<Value, RuntimeError> {
+        match self {
+            Function::BuiltIn(built_in_function) => {
+                built_in_function.call(arguments, source, context)
+            }
+            Function::ContextDefined(function_node) => {
+                let call_context = Context::with_variables_from(function_node.context())?;
+
+                call_context.inherit_from(context)?;
+
+                let parameter_argument_pairs =
+                    function_node.parameters().iter().zip(arguments.iter());
+
+                for (identifier, value) in parameter_argument_pairs {
+                    call_context.set_value(identifier.clone(), value.clone())?;
+                }
+
+                function_node.body().run(source, &call_context)
+            }
+        }
+    }
+
     pub fn parameters(&self) -> Option<&Vec<Identifier>> {
         if let Function::ContextDefined(function) = self {
             Some(function.parameters())
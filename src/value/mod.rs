@@ -21,14 +21,16 @@ use std::{
 };
 
 pub use self::{
-    enum_instance::EnumInstance, function::Function, list::List, map::Map,
-    struct_instance::StructInstance,
+    enum_instance::EnumInstance, function::Function, list::List, map::Map, ordered_map::OrderedMap,
+    set::Set, struct_instance::StructInstance,
 };
 
 pub mod enum_instance;
 pub mod function;
 pub mod list;
 pub mod map;
+pub mod ordered_map;
+pub mod set;
 pub mod struct_instance;
 
 /// Dust value representation.
@@ -45,7 +47,9 @@ pub enum Value {
     Integer(i64),
     List(List),
     Map(Map),
+    OrderedMap(OrderedMap),
     Range(RangeInclusive<i64>),
+    Set(Set),
     String(String),
     Struct(StructInstance),
 }
@@ -63,6 +67,22 @@ impl Value {
         ))
     }
 
+    pub fn ok(value: Value) -> Value {
+        Value::Enum(EnumInstance::new(
+            Identifier::new("Result"),
+            Identifier::new("Ok"),
+            Some(value),
+        ))
+    }
+
+    pub fn error(value: Value) -> Value {
+        Value::Enum(EnumInstance::new(
+            Identifier::new("Result"),
+            Identifier::new("Error"),
+            Some(value),
+        ))
+    }
+
     pub fn string<T: Into<String>>(string: T) -> Self {
         Value::String(string.into())
     }
@@ -97,13 +117,17 @@ impl Value {
                     Type::Map(Some(type_map))
                 }
             }
+            Value::OrderedMap(_) => Type::Map(None),
+            Value::Set(_) => Type::custom(Identifier::new("Set"), Vec::with_capacity(0)),
             Value::Function(function) => function.r#type().clone(),
             Value::String(_) => Type::String,
             Value::Float(_) => Type::Float,
             Value::Integer(_) => Type::Integer,
             Value::Boolean(_) => Type::Boolean,
             Value::Range(_) => todo!(),
-            Value::Struct(_) => todo!(),
+            Value::Struct(struct_instance) => {
+                Type::custom(struct_instance.name().clone(), Vec::with_capacity(0))
+            }
             Value::Enum(enum_instance) => {
                 let arguments = if let Some(value) = enum_instance.value() {
                     vec![value.r#type()?]
@@ -168,6 +192,22 @@ impl Value {
         }
     }
 
+    /// Copies the only character of a string stored in `self`, or returns
+    /// `Err` if `self` is not a `Value::String` of exactly one character.
+    pub fn as_char(&self) -> Result<char, ValidationError> {
+        if let Value::String(string) = self {
+            let mut chars = string.chars();
+
+            if let (Some(char), None) = (chars.next(), chars.next()) {
+                return Ok(char);
+            }
+        }
+
+        Err(ValidationError::ExpectedCharacter {
+            actual: self.clone(),
+        })
+    }
+
     /// Copies the value stored in `self` as `i64`, or returns `Err` if `self`
     /// is not a `Value::Int`
     pub fn as_integer(&self) -> Result<i64, ValidationError> {
@@ -249,6 +289,17 @@ impl Value {
         }
     }
 
+    /// Borrows the value stored in `self` as `Set`, or returns `Err` if `self`
+    /// is not a `Value::Set`.
+    pub fn as_set(&self) -> Result<&Set, ValidationError> {
+        match self {
+            Value::Set(set) => Ok(set),
+            value => Err(ValidationError::ExpectedSet {
+                actual: value.clone(),
+            }),
+        }
+    }
+
     /// Borrows the value stored in `self` as `Function`, or returns `Err` if
     /// `self` is not a `Value::Function`.
     pub fn as_function(&self) -> Result<&Function, ValidationError> {
@@ -261,6 +312,8 @@ impl Value {
     }
 
     /// Return the sum of `self` and `other`.
+    ///
+    /// Integer addition saturates at the integer bounds rather than wrapping.
     pub fn add(self, other: Self, position: SourcePosition) -> Result<Value, ValidationError> {
         match (self, other) {
             (Value::Float(left), Value::Float(right)) => Ok(Value::Float(left + right)),
@@ -284,6 +337,8 @@ impl Value {
     }
 
     /// Return the difference of `self` and `other`.
+    ///
+    /// Integer subtraction saturates at the integer bounds rather than wrapping.
     pub fn subtract(self, other: Self, position: SourcePosition) -> Result<Value, ValidationError> {
         match (self, other) {
             (Value::Float(left), Value::Float(right)) => Ok(Value::Float(left - right)),
@@ -363,8 +418,10 @@ impl PartialEq for Value {
             (Value::String(left), Value::String(right)) => left == right,
             (Value::List(left), Value::List(right)) => left == right,
             (Value::Map(left), Value::Map(right)) => left == right,
+            (Value::OrderedMap(left), Value::OrderedMap(right)) => left == right,
             (Value::Function(left), Value::Function(right)) => left == right,
             (Value::Range(left), Value::Range(right)) => left == right,
+            (Value::Set(left), Value::Set(right)) => left == right,
             (Value::Struct(left), Value::Struct(right)) => left == right,
             (Value::Enum(left), Value::Enum(right)) => left == right,
             _ => false,
@@ -401,6 +458,10 @@ impl Ord for Value {
             (Value::List(_), _) => Ordering::Greater,
             (Value::Map(left), Value::Map(right)) => left.cmp(right),
             (Value::Map(_), _) => Ordering::Greater,
+            (Value::OrderedMap(left), Value::OrderedMap(right)) => left.cmp(right),
+            (Value::OrderedMap(_), _) => Ordering::Greater,
+            (Value::Set(left), Value::Set(right)) => left.cmp(right),
+            (Value::Set(_), _) => Ordering::Greater,
             (Value::Function(left), Value::Function(right)) => left.cmp(right),
             (Value::Function(_), _) => Ordering::Greater,
             (Value::Struct(left), Value::Struct(right)) => left.cmp(right),
@@ -453,10 +514,43 @@ impl Serialize for Value {
 
                 map.end()
             }
+            Value::OrderedMap(ordered_map) => {
+                let entries = if let Ok(entries) = ordered_map.inner() {
+                    entries
+                } else {
+                    return Err(serde::ser::Error::custom("failed to obtain a read lock"));
+                };
+
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+
+                for (key, value) in entries.iter() {
+                    map.serialize_entry(key, value)?;
+                }
+
+                map.end()
+            }
+            Value::Set(set) => {
+                let values = if let Ok(values) = set.values() {
+                    values
+                } else {
+                    return Err(serde::ser::Error::custom("failed to obtain a read lock"));
+                };
+
+                let mut list = serializer.serialize_tuple(values.len())?;
+
+                for value in values.iter() {
+                    list.serialize_element(value)?;
+                }
+
+                list.end()
+            }
             Value::Function(inner) => inner.serialize(serializer),
             Value::Struct(inner) => inner.serialize(serializer),
             Value::Range(range) => range.serialize(serializer),
-            Value::Enum(_) => todo!(),
+            Value::Enum(_) if self.is_none() => serializer.serialize_unit(),
+            Value::Enum(enum_instance) => Err(serde::ser::Error::custom(format!(
+                "the enum {enum_instance} cannot be serialized"
+            ))),
         }
     }
 }
@@ -470,9 +564,11 @@ impl Display for Value {
             Value::Boolean(boolean) => write!(f, "{boolean}"),
             Value::List(list) => write!(f, "{list}"),
             Value::Map(map) => write!(f, "{map}"),
+            Value::OrderedMap(ordered_map) => write!(f, "{ordered_map}"),
             Value::Function(function) => write!(f, "{function}"),
             Value::Struct(structure) => write!(f, "{structure}"),
             Value::Range(range) => write!(f, "{}..{}", range.start(), range.end()),
+            Value::Set(set) => write!(f, "{set}"),
             Value::Enum(enum_instance) => write!(f, "{enum_instance}"),
         }
     }
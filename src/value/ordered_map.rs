@@ -0,0 +1,101 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use indexmap::IndexMap;
+
+use crate::{error::rw_lock_error::RwLockError, Identifier, Value};
+
+/// A map that remembers the order in which keys were inserted.
+///
+/// Like [List][crate::List], an ordered map is shared between its clones, so
+/// setting or removing a key is visible through every reference to it.
+#[derive(Debug, Clone)]
+pub struct OrderedMap(Arc<RwLock<IndexMap<Identifier, Value>>>);
+
+impl Default for OrderedMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrderedMap {
+    pub fn new() -> Self {
+        OrderedMap(Arc::new(RwLock::new(IndexMap::new())))
+    }
+
+    pub fn with_entries(entries: IndexMap<Identifier, Value>) -> Self {
+        OrderedMap(Arc::new(RwLock::new(entries)))
+    }
+
+    pub fn inner(&self) -> Result<RwLockReadGuard<IndexMap<Identifier, Value>>, RwLockError> {
+        Ok(self.0.read()?)
+    }
+
+    pub fn inner_mut(&self) -> Result<RwLockWriteGuard<IndexMap<Identifier, Value>>, RwLockError> {
+        Ok(self.0.write()?)
+    }
+
+    pub fn get(&self, key: &Identifier) -> Result<Option<Value>, RwLockError> {
+        Ok(self.inner()?.get(key).cloned())
+    }
+
+    pub fn set(&self, key: Identifier, value: Value) -> Result<(), RwLockError> {
+        self.inner_mut()?.insert(key, value);
+
+        Ok(())
+    }
+
+    /// Removes a key while keeping the remaining keys in insertion order.
+    pub fn remove(&self, key: &Identifier) -> Result<Option<Value>, RwLockError> {
+        Ok(self.inner_mut()?.shift_remove(key))
+    }
+}
+
+impl Display for OrderedMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let entries = self.inner().map_err(|_| fmt::Error)?;
+
+        write!(f, "{{")?;
+
+        for (index, (key, value)) in entries.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{key}: {value}")?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+impl Eq for OrderedMap {}
+
+impl PartialEq for OrderedMap {
+    fn eq(&self, other: &Self) -> bool {
+        if let (Ok(left), Ok(right)) = (self.inner(), other.inner()) {
+            left.len() == right.len() && left.iter().eq(right.iter())
+        } else {
+            true
+        }
+    }
+}
+
+impl PartialOrd for OrderedMap {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedMap {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if let (Ok(left), Ok(right)) = (self.inner(), other.inner()) {
+            left.iter().cmp(right.iter())
+        } else {
+            Ordering::Equal
+        }
+    }
+}
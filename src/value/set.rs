@@ -0,0 +1,89 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    fmt::{self, Display, Formatter},
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use crate::{error::rw_lock_error::RwLockError, Value};
+
+/// A sorted collection of unique values.
+///
+/// Like [List][crate::List], a set is shared between its clones, so adding or
+/// removing a value is visible through every reference to it.
+///
+/// Values are ordered by their contents when they are added, so changing a
+/// list after putting it in a set can leave the set out of order.
+#[derive(Debug, Clone)]
+pub struct Set(Arc<RwLock<BTreeSet<Value>>>);
+
+impl Default for Set {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(clippy::mutable_key_type)]
+impl Set {
+    pub fn new() -> Self {
+        Set(Arc::new(RwLock::new(BTreeSet::new())))
+    }
+
+    pub fn with_values(values: BTreeSet<Value>) -> Self {
+        Set(Arc::new(RwLock::new(values)))
+    }
+
+    pub fn values(&self) -> Result<RwLockReadGuard<BTreeSet<Value>>, RwLockError> {
+        Ok(self.0.read()?)
+    }
+
+    pub fn values_mut(&self) -> Result<RwLockWriteGuard<BTreeSet<Value>>, RwLockError> {
+        Ok(self.0.write()?)
+    }
+}
+
+impl Display for Set {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let values = self.values().map_err(|_| fmt::Error)?;
+
+        write!(f, "{{")?;
+
+        for (index, value) in values.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{value}")?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+impl Eq for Set {}
+
+impl PartialEq for Set {
+    fn eq(&self, other: &Self) -> bool {
+        if let (Ok(left), Ok(right)) = (self.values(), other.values()) {
+            *left == *right
+        } else {
+            true
+        }
+    }
+}
+
+impl PartialOrd for Set {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Set {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if let (Ok(left), Ok(right)) = (self.values(), other.values()) {
+            left.cmp(&right)
+        } else {
+            Ordering::Equal
+        }
+    }
+}
@@ -14,6 +14,14 @@ impl StructInstance {
     pub fn new(name: Identifier, map: Map) -> Self {
         StructInstance { name, map }
     }
+
+    pub fn name(&self) -> &Identifier {
+        &self.name
+    }
+
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
 }
 
 impl Display for StructInstance {
@@ -0,0 +1,69 @@
+use dust_lang::{
+    error::{RuntimeError, ValidationError},
+    *,
+};
+
+#[test]
+fn to_char() {
+    assert_eq!(
+        interpret("byte:to_char(65)"),
+        Ok(Value::ok(Value::string("A")))
+    );
+    assert_eq!(
+        interpret("byte:to_char(200)"),
+        Ok(Value::error(Value::string("200 is not an ASCII byte.")))
+    );
+}
+
+#[test]
+fn char_to_byte() {
+    assert_eq!(
+        interpret("char:to_byte('A')"),
+        Ok(Value::ok(Value::Integer(65)))
+    );
+    assert_eq!(
+        interpret("char:to_byte('é')"),
+        Ok(Value::error(Value::string(
+            "'é' is not an ASCII character."
+        )))
+    );
+    assert!(matches!(
+        interpret("char:to_byte('AB')"),
+        Err(Error::Runtime(RuntimeError::ValidationFailure(
+            ValidationError::ExpectedCharacter { .. }
+        )))
+    ));
+}
+
+#[test]
+fn predicates() {
+    assert_eq!(interpret("byte:is_alpha(97)"), Ok(Value::Boolean(true)));
+    assert_eq!(interpret("byte:is_alpha(49)"), Ok(Value::Boolean(false)));
+    assert_eq!(interpret("byte:is_digit(49)"), Ok(Value::Boolean(true)));
+    assert_eq!(interpret("byte:is_digit(97)"), Ok(Value::Boolean(false)));
+    assert_eq!(
+        interpret("byte:is_whitespace(32)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("byte:is_whitespace(10)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("byte:is_whitespace(95)"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("byte:is_alphanumeric(90)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("byte:is_alphanumeric(48)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("byte:is_alphanumeric(45)"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(interpret("byte:is_alpha(233)"), Ok(Value::Boolean(false)));
+}
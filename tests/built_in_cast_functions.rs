@@ -0,0 +1,63 @@
+use dust_lang::*;
+
+#[test]
+fn cast_to_integer() {
+    assert_eq!(
+        interpret("cast:int(42.9)"),
+        Ok(Value::ok(Value::Integer(42)))
+    );
+    assert_eq!(
+        interpret("cast:int('42')"),
+        Ok(Value::ok(Value::Integer(42)))
+    );
+    assert_eq!(
+        interpret("cast:int(true)"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("cast:int('forty-two')"),
+        Ok(Value::error(Value::string("Cannot convert str to int.")))
+    );
+}
+
+#[test]
+fn cast_to_float() {
+    assert_eq!(
+        interpret("cast:float(42)"),
+        Ok(Value::ok(Value::Float(42.0)))
+    );
+    assert_eq!(
+        interpret("cast:float('0.5')"),
+        Ok(Value::ok(Value::Float(0.5)))
+    );
+    assert_eq!(
+        interpret("cast:float([1])"),
+        Ok(Value::error(Value::string(
+            "Cannot convert [int] to float."
+        )))
+    );
+}
+
+#[test]
+fn cast_to_string() {
+    assert_eq!(
+        interpret("cast:str(42)"),
+        Ok(Value::ok(Value::string("42")))
+    );
+}
+
+#[test]
+fn cast_to_boolean() {
+    assert_eq!(
+        interpret("cast:bool(0)"),
+        Ok(Value::ok(Value::Boolean(false)))
+    );
+    assert_eq!(
+        interpret("cast:bool('true')"),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert_eq!(
+        interpret("cast:bool('yes')"),
+        Ok(Value::error(Value::string("Cannot convert str to bool.")))
+    );
+}
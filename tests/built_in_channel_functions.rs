@@ -0,0 +1,97 @@
+use dust_lang::{error::ValidationError, *};
+
+#[test]
+fn send_and_recv_in_order() {
+    assert_eq!(
+        interpret(
+            "
+            pair = channel:new()
+            sender = pair:0
+            receiver = pair:1
+
+            channel:send(sender, 1)
+            channel:send(sender, 2)
+            channel:send(sender, 3)
+
+            first = channel:recv(receiver)
+            second = channel:recv(receiver)
+            third = channel:recv(receiver)
+
+            [first, second, third]
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::some(Value::Integer(1)),
+            Value::some(Value::Integer(2)),
+            Value::some(Value::Integer(3)),
+        ])))
+    );
+}
+
+#[test]
+fn try_recv_empty() {
+    assert_eq!(
+        interpret(
+            "
+            pair = channel:new()
+            channel:try_recv(pair:1)
+            "
+        ),
+        Ok(Value::none())
+    );
+}
+
+#[test]
+fn closed_channel() {
+    assert_eq!(
+        interpret(
+            "
+            pair = channel:new()
+            sender = pair:0
+            receiver = pair:1
+
+            channel:send(sender, 'hi')
+            channel:close(sender)
+
+            sent = channel:send(sender, 'bye')
+            first = channel:recv(receiver)
+            second = channel:recv(receiver)
+
+            [sent, first, second]
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::Boolean(false),
+            Value::some(Value::string("hi")),
+            Value::none(),
+        ])))
+    );
+}
+
+#[test]
+fn send_with_receiver() {
+    let result = interpret(
+        "
+        pair = channel:new()
+        channel:send(pair:1, 1)
+        ",
+    );
+
+    assert!(matches!(
+        result,
+        Err(Error::Validation(ValidationError::TypeCheck { .. }))
+    ));
+}
+
+#[test]
+fn expected_struct_instance_report() {
+    let source = "channel:send(1, 1)";
+    let error = ValidationError::ExpectedStructInstance {
+        name: Identifier::new("Sender"),
+        actual: Value::Integer(1),
+    };
+
+    assert!(error
+        .create_report(source)
+        .contains("Expected an instance of"));
+}
@@ -0,0 +1,51 @@
+use dust_lang::*;
+
+#[test]
+fn parse() {
+    assert_eq!(
+        interpret("date:parse('2024-01-15T00:00:00Z')"),
+        Ok(Value::ok(Value::Integer(1705276800)))
+    );
+    assert_eq!(
+        interpret("date:parse('2024-01-15T12:30:00Z')"),
+        Ok(Value::ok(Value::Integer(1705321800)))
+    );
+}
+
+#[test]
+fn parse_invalid() {
+    let result = interpret("date:parse('yesterday')").unwrap();
+
+    if let Value::Enum(enum_instance) = result {
+        assert_eq!(enum_instance.variant(), &Identifier::new("Error"));
+    } else {
+        panic!("Expected a Result.");
+    }
+}
+
+#[test]
+fn format() {
+    assert_eq!(
+        interpret("date:format(0, '%Y-%m-%d')"),
+        Ok(Value::string("1970-01-01"))
+    );
+    assert_eq!(
+        interpret("date:format(1705321800, '%Y-%m-%dT%H:%M:%SZ')"),
+        Ok(Value::string("2024-01-15T12:30:00Z"))
+    );
+}
+
+#[test]
+fn round_trip() {
+    assert_eq!(
+        interpret("date:parse(date:format(1705321800, '%Y-%m-%dT%H:%M:%SZ'))"),
+        Ok(Value::ok(Value::Integer(1705321800)))
+    );
+}
+
+#[test]
+fn now_utc() {
+    let result = interpret("date:now_utc()").unwrap();
+
+    assert!(result.as_integer().unwrap() > 1705276800);
+}
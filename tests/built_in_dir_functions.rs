@@ -0,0 +1,44 @@
+use std::{env::temp_dir, path::Path};
+
+use dust_lang::*;
+
+#[test]
+fn create_list_and_remove() {
+    let root = temp_dir().join(format!("dust_dir_test_{}", std::process::id()));
+    let root = root.to_string_lossy();
+
+    assert_eq!(
+        interpret(&format!("dir:create('{root}')")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert_eq!(
+        interpret(&format!("dir:list('{root}')")),
+        Ok(Value::ok(Value::List(List::new())))
+    );
+    assert_eq!(
+        interpret(&format!("dir:create('{root}/sub')")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert_eq!(
+        interpret(&format!("dir:list('{root}')")),
+        Ok(Value::ok(Value::List(List::with_items(vec![
+            Value::string("sub")
+        ]))))
+    );
+    assert_eq!(
+        interpret(&format!("dir:remove('{root}', true)")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert!(!Path::new(root.as_ref()).exists());
+}
+
+#[test]
+fn list_missing_directory() {
+    let result = interpret("dir:list('/this/directory/does/not/exist')").unwrap();
+
+    if let Value::Enum(enum_instance) = result {
+        assert_eq!(enum_instance.variant(), &Identifier::new("Error"));
+    } else {
+        panic!("Expected a Result.");
+    }
+}
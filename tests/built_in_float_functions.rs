@@ -0,0 +1,54 @@
+use dust_lang::*;
+
+#[test]
+fn trigonometry() {
+    assert_eq!(interpret("float:sin(0.0)"), Ok(Value::Float(0.0)));
+    assert_eq!(interpret("float:cos(0.0)"), Ok(Value::Float(1.0)));
+    assert_eq!(
+        interpret("float:atan2(1.0, 1.0)"),
+        Ok(Value::Float(1f64.atan2(1.0)))
+    );
+}
+
+#[test]
+fn roots_and_logarithms() {
+    assert_eq!(interpret("float:sqrt(4.0)"), Ok(Value::Float(2.0)));
+    assert_eq!(interpret("float:cbrt(27.0)"), Ok(Value::Float(3.0)));
+    assert_eq!(interpret("float:ln(1.0)"), Ok(Value::Float(0.0)));
+    assert_eq!(interpret("float:log2(8.0)"), Ok(Value::Float(3.0)));
+    assert_eq!(interpret("float:log10(100.0)"), Ok(Value::Float(2.0)));
+    assert_eq!(interpret("float:hypot(3.0, 4.0)"), Ok(Value::Float(5.0)));
+}
+
+#[test]
+fn rounding() {
+    assert_eq!(interpret("float:floor(2.9)"), Ok(Value::Float(2.0)));
+    assert_eq!(interpret("float:ceil(2.1)"), Ok(Value::Float(3.0)));
+    assert_eq!(interpret("float:round(2.5)"), Ok(Value::Float(3.0)));
+    assert_eq!(interpret("float:trunc(-2.9)"), Ok(Value::Float(-2.0)));
+}
+
+#[test]
+fn min_max_and_clamp() {
+    assert_eq!(interpret("float:min(1.0, 2.0)"), Ok(Value::Float(1.0)));
+    assert_eq!(interpret("float:max(1.0, 2.0)"), Ok(Value::Float(2.0)));
+    assert_eq!(
+        interpret("float:clamp(5.0, 0.0, 1.0)"),
+        Ok(Value::Float(1.0))
+    );
+    assert_eq!(
+        interpret("float:clamp(-5.0, 0.0, 1.0)"),
+        Ok(Value::Float(0.0))
+    );
+    assert_eq!(
+        interpret("float:clamp(0.5, 2.0, 1.0)"),
+        Ok(Value::Float(1.0))
+    );
+}
+
+#[test]
+fn sign() {
+    assert_eq!(interpret("float:sign(-3.5)"), Ok(Value::Float(-1.0)));
+    assert_eq!(interpret("float:sign(2.0)"), Ok(Value::Float(1.0)));
+    assert_eq!(interpret("float:sign(0.0)"), Ok(Value::Float(0.0)));
+}
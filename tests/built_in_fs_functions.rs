@@ -0,0 +1,132 @@
+use std::{env::temp_dir, process::Command};
+
+use dust_lang::{error::RuntimeError, *};
+
+fn interpret_with_io(source: &str) -> Result<Value, Error> {
+    let context = Context::default();
+
+    context.set_allow_io(true);
+
+    interpret_with_context(source, context)
+}
+
+#[test]
+fn write_read_append_and_remove() {
+    let directory = temp_dir().join(format!("dust_fs_test_{}", std::process::id()));
+    let path = directory.join("file.txt");
+    let directory = directory.to_string_lossy();
+    let path = path.to_string_lossy();
+
+    assert_eq!(
+        interpret_with_io(&format!("dir:create('{directory}')")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:exists('{path}')")),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:write_file('{path}', 'hello')")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:read_file('{path}')")),
+        Ok(Value::string("hello"))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:append_file('{path}', ' world')")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:read_file('{path}')")),
+        Ok(Value::string("hello world"))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:write_file('{path}', 'bye')")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:read_file('{path}')")),
+        Ok(Value::string("bye"))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:remove_file('{path}')")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("fs:exists('{path}')")),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret_with_io(&format!("dir:remove('{directory}', true)")),
+        Ok(Value::ok(Value::Boolean(true)))
+    );
+}
+
+#[test]
+fn remove_missing_file() {
+    let result = interpret_with_io("fs:remove_file('/this/file/does/not/exist')").unwrap();
+
+    if let Value::Enum(enum_instance) = result {
+        assert_eq!(enum_instance.variant(), &Identifier::new("Error"));
+    } else {
+        panic!("Expected a Result.");
+    }
+}
+
+#[test]
+fn sandbox() {
+    let path = temp_dir().join(format!("dust_fs_sandbox_test_{}", std::process::id()));
+    let path = path.to_string_lossy();
+
+    for source in [
+        format!("fs:write_file('{path}', 'hello')"),
+        format!("fs:append_file('{path}', 'hello')"),
+        format!("fs:remove_file('{path}')"),
+        format!("fs:read_file('{path}')"),
+        format!("fs:exists('{path}')"),
+        "fs:write_file('file.txt', 'hello')".to_string(),
+        "fs:remove_file('file.txt')".to_string(),
+        "fs:read_file('../Cargo.toml')".to_string(),
+        "fs:exists('examples/../../Cargo.toml')".to_string(),
+    ] {
+        assert!(
+            matches!(
+                interpret(&source),
+                Err(Error::Runtime(RuntimeError::IoNotAllowed { .. }))
+            ),
+            "{source} should not be allowed"
+        );
+    }
+
+    assert_eq!(
+        interpret("fs:exists('./Cargo.toml')"),
+        Ok(Value::Boolean(true))
+    );
+    assert!(interpret("fs:read_file('examples/assets/seaCreatures.json')").is_ok());
+}
+
+#[test]
+fn allow_io_flag() {
+    let path = temp_dir().join(format!("dust_fs_flag_test_{}", std::process::id()));
+    let source = format!("fs:write_file('{}', 'hello')", path.to_string_lossy());
+    let run = |allow_io: bool| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_dust"));
+
+        if allow_io {
+            command.arg("--allow-io");
+        }
+
+        command.args(["-c", &source]).output().unwrap()
+    };
+
+    run(false);
+
+    assert!(!path.exists());
+
+    run(true);
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "hello");
+
+    std::fs::remove_file(&path).unwrap();
+}
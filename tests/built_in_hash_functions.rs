@@ -0,0 +1,53 @@
+use dust_lang::*;
+
+#[test]
+fn fnv1a() {
+    assert_eq!(
+        interpret("hash:fnv1a('hello')"),
+        Ok(Value::Integer(0xa430d84680aabd0b_u64 as i64))
+    );
+    assert_eq!(
+        interpret("hash:fnv1a('')"),
+        Ok(Value::Integer(0xcbf29ce484222325_u64 as i64))
+    );
+    assert_eq!(
+        interpret("hash:fnv1a('hello') == hash:fnv1a('Hello')"),
+        Ok(Value::Boolean(false))
+    );
+}
+
+#[test]
+fn xxhash() {
+    assert_eq!(
+        interpret("hash:xxhash('')"),
+        Ok(Value::Integer(0xef46db3751d8e999_u64 as i64))
+    );
+    assert_eq!(
+        interpret("hash:xxhash('hello') == hash:xxhash('Hello')"),
+        Ok(Value::Boolean(false))
+    );
+}
+
+#[test]
+fn bytes_variants_match_strings() {
+    assert_eq!(
+        interpret("hash:fnv1a_bytes(str:as_bytes('hello'))"),
+        interpret("hash:fnv1a('hello')")
+    );
+    assert_eq!(
+        interpret("hash:xxhash_bytes(str:as_bytes('hello'))"),
+        interpret("hash:xxhash('hello')")
+    );
+}
+
+#[test]
+fn hashes_are_stable() {
+    assert_eq!(
+        interpret("hash:xxhash('dust') == hash:xxhash('dust')"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("hash:fnv1a('dust')"),
+        interpret("hash:fnv1a('dust')")
+    );
+}
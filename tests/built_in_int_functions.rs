@@ -0,0 +1,335 @@
+use dust_lang::{error::RuntimeError, *};
+
+#[test]
+fn addition_saturates() {
+    assert_eq!(
+        interpret("9223372036854775807 + 1"),
+        Ok(Value::Integer(i64::MAX))
+    );
+    assert_eq!(
+        interpret("-9223372036854775808 - 1"),
+        Ok(Value::Integer(i64::MIN))
+    );
+}
+
+#[test]
+fn wrapping() {
+    assert_eq!(
+        interpret("int:wrapping_add(9223372036854775807, 1)"),
+        Ok(Value::Integer(i64::MIN))
+    );
+    assert_eq!(
+        interpret("int:wrapping_sub(-9223372036854775808, 1)"),
+        Ok(Value::Integer(i64::MAX))
+    );
+    assert_eq!(
+        interpret("int:wrapping_mul(9223372036854775807, 2)"),
+        Ok(Value::Integer(-2))
+    );
+}
+
+#[test]
+fn checked() {
+    assert_eq!(
+        interpret("int:checked_add(1, 2)"),
+        Ok(Value::ok(Value::Integer(3)))
+    );
+    assert_eq!(
+        interpret("int:checked_add(9223372036854775807, 1)"),
+        Ok(Value::error(Value::string("Integer overflow.")))
+    );
+    assert_eq!(
+        interpret("int:checked_sub(-9223372036854775808, 1)"),
+        Ok(Value::error(Value::string("Integer overflow.")))
+    );
+    assert_eq!(
+        interpret("int:checked_mul(3, 4)"),
+        Ok(Value::ok(Value::Integer(12)))
+    );
+    assert_eq!(
+        interpret("int:checked_div(7, -2)"),
+        Ok(Value::ok(Value::Integer(-3)))
+    );
+    assert_eq!(
+        interpret("int:checked_rem(7, -2)"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("int:checked_div(1, 0)"),
+        Ok(Value::error(Value::string("Division by zero.")))
+    );
+    assert_eq!(
+        interpret("int:checked_rem(1, 0)"),
+        Ok(Value::error(Value::string("Division by zero.")))
+    );
+    assert_eq!(
+        interpret("int:checked_div(-9223372036854775808, -1)"),
+        Ok(Value::error(Value::string("Integer overflow.")))
+    );
+    assert_eq!(
+        interpret("int:checked_rem(-9223372036854775808, -1)"),
+        Ok(Value::error(Value::string("Integer overflow.")))
+    );
+}
+
+#[test]
+fn abs() {
+    assert_eq!(interpret("int:abs(-42)"), Ok(Value::ok(Value::Integer(42))));
+    assert_eq!(
+        interpret("int:abs(-9223372036854775808)"),
+        Ok(Value::error(Value::string("Integer overflow.")))
+    );
+    assert_eq!(interpret("float:abs(-1.5)"), Ok(Value::Float(1.5)));
+}
+
+#[test]
+fn radix_formatting() {
+    assert_eq!(interpret("int:to_hex(255)"), Ok(Value::string("ff")));
+    assert_eq!(interpret("int:to_hex_upper(255)"), Ok(Value::string("FF")));
+    assert_eq!(interpret("int:to_binary(10)"), Ok(Value::string("1010")));
+    assert_eq!(interpret("int:to_octal(8)"), Ok(Value::string("10")));
+    assert_eq!(interpret("int:to_hex(-255)"), Ok(Value::string("-ff")));
+    assert_eq!(
+        interpret("int:to_binary(-9223372036854775808)"),
+        Ok(Value::string(format!("-1{}", "0".repeat(63))))
+    );
+    assert_eq!(
+        interpret("int:format(-42, 16, 8, '0')"),
+        Ok(Value::string("-0000002a"))
+    );
+    assert_eq!(
+        interpret("int:format(42, 36, 0, ' ')"),
+        Ok(Value::string("16"))
+    );
+}
+
+#[test]
+fn invalid_radix() {
+    assert_eq!(
+        interpret("int:format(42, 37, 0, '0')"),
+        Err(Error::Runtime(RuntimeError::InvalidRadix { radix: 37 }))
+    );
+    assert_eq!(
+        interpret("int:format(42, 1, 0, '0')"),
+        Err(Error::Runtime(RuntimeError::InvalidRadix { radix: 1 }))
+    );
+}
+
+#[test]
+fn mul_high() {
+    assert_eq!(
+        interpret("int:mul_high(9223372036854775807, 2)"),
+        Ok(Value::Integer(0))
+    );
+    assert_eq!(
+        interpret("int:mul_high(-9223372036854775808, 4)"),
+        Ok(Value::Integer(-2))
+    );
+}
+
+#[test]
+fn full_mul() {
+    assert_eq!(
+        interpret("int:full_mul(9223372036854775807, 2)"),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(0),
+            Value::Integer(-2),
+        ])))
+    );
+}
+
+#[test]
+fn div_rem() {
+    assert_eq!(
+        interpret("int:div_rem(17, 5)"),
+        Ok(Value::ok(Value::List(List::with_items(vec![
+            Value::Integer(3),
+            Value::Integer(2),
+        ]))))
+    );
+    assert_eq!(
+        interpret("int:div_rem(17, 0)"),
+        Ok(Value::error(Value::string("Division by zero.")))
+    );
+    assert_eq!(
+        interpret("int:div_rem(-9223372036854775808, -1)"),
+        Ok(Value::error(Value::string("Integer overflow.")))
+    );
+}
+
+#[test]
+fn isqrt() {
+    assert_eq!(interpret("int:isqrt(9)"), Ok(Value::ok(Value::Integer(3))));
+    assert_eq!(interpret("int:isqrt(10)"), Ok(Value::ok(Value::Integer(3))));
+    assert_eq!(
+        interpret("int:isqrt(9223372036854775807)"),
+        Ok(Value::ok(Value::Integer(3037000499)))
+    );
+    assert_eq!(
+        interpret("int:isqrt(-1)"),
+        Ok(Value::error(Value::string(
+            "Cannot take the square root of a negative integer."
+        )))
+    );
+}
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(interpret("int:gcd(12, 8)"), Ok(Value::Integer(4)));
+    assert_eq!(interpret("int:gcd(-12, 8)"), Ok(Value::Integer(4)));
+    assert_eq!(interpret("int:gcd(0, -5)"), Ok(Value::Integer(5)));
+    assert_eq!(
+        interpret("int:lcm(4, 6)"),
+        Ok(Value::ok(Value::Integer(12)))
+    );
+    assert_eq!(
+        interpret("int:lcm(-4, 0)"),
+        Ok(Value::ok(Value::Integer(0)))
+    );
+    assert_eq!(
+        interpret("int:lcm(9223372036854775807, 2)"),
+        Ok(Value::error(Value::string("Integer overflow.")))
+    );
+}
+
+#[test]
+fn is_prime() {
+    for prime in [
+        2_i64,
+        3,
+        5,
+        7,
+        13,
+        97,
+        7919,
+        2147483647,
+        9223372036854775783,
+    ] {
+        assert_eq!(
+            interpret(&format!("int:is_prime({prime})")),
+            Ok(Value::Boolean(true))
+        );
+    }
+
+    for composite in [-7_i64, 0, 1, 4, 9, 91, 561, 3215031751, 9223372036854775807] {
+        assert_eq!(
+            interpret(&format!("int:is_prime({composite})")),
+            Ok(Value::Boolean(false))
+        );
+    }
+}
+
+#[test]
+fn bits() {
+    assert_eq!(interpret("int:get_bit(5, 0)"), Ok(Value::Boolean(true)));
+    assert_eq!(interpret("int:get_bit(5, 1)"), Ok(Value::Boolean(false)));
+    assert_eq!(interpret("int:get_bit(-1, 63)"), Ok(Value::Boolean(true)));
+    assert_eq!(interpret("int:set_bit(4, 0, true)"), Ok(Value::Integer(5)));
+    assert_eq!(interpret("int:set_bit(5, 2, false)"), Ok(Value::Integer(1)));
+    assert_eq!(interpret("int:toggle_bit(7, 0)"), Ok(Value::Integer(6)));
+    assert_eq!(
+        interpret("int:bit_range(65295, 4, 12)"),
+        Ok(Value::Integer(240))
+    );
+    assert_eq!(
+        interpret("int:bit_range(-1, 0, 64)"),
+        Ok(Value::Integer(-1))
+    );
+    assert_eq!(interpret("int:bit_range(-1, 3, 3)"), Ok(Value::Integer(0)));
+}
+
+#[test]
+fn invalid_bit_position() {
+    assert_eq!(
+        interpret("int:get_bit(1, 64)"),
+        Err(Error::Runtime(RuntimeError::InvalidBitPosition {
+            position: 64
+        }))
+    );
+    assert_eq!(
+        interpret("int:toggle_bit(1, -1)"),
+        Err(Error::Runtime(RuntimeError::InvalidBitPosition {
+            position: -1
+        }))
+    );
+    assert_eq!(
+        interpret("int:bit_range(1, 4, 2)"),
+        Err(Error::Runtime(RuntimeError::InvalidBitPosition {
+            position: 2
+        }))
+    );
+}
+
+#[test]
+fn shifts() {
+    assert_eq!(interpret("int:shl(5, 0)"), Ok(Value::ok(Value::Integer(5))));
+    assert_eq!(
+        interpret("int:shl(1, 63)"),
+        Ok(Value::ok(Value::Integer(i64::MIN)))
+    );
+    assert_eq!(
+        interpret("int:shr(-1, 63)"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("int:sar(-1, 63)"),
+        Ok(Value::ok(Value::Integer(-1)))
+    );
+    assert_eq!(
+        interpret("int:sar(-8, 1)"),
+        Ok(Value::ok(Value::Integer(-4)))
+    );
+    assert_eq!(
+        interpret("int:shl(1, 64)"),
+        Ok(Value::error(Value::string(
+            "Cannot shift by 64. Use a number from 0 to 63."
+        )))
+    );
+    assert_eq!(
+        interpret("int:shr(1, -1)"),
+        Ok(Value::error(Value::string(
+            "Cannot shift by -1. Use a number from 0 to 63."
+        )))
+    );
+}
+
+#[test]
+fn bitwise() {
+    assert_eq!(interpret("int:bit_and(12, 10)"), Ok(Value::Integer(8)));
+    assert_eq!(interpret("int:bit_or(12, 10)"), Ok(Value::Integer(14)));
+    assert_eq!(interpret("int:bit_xor(12, 10)"), Ok(Value::Integer(6)));
+    assert_eq!(interpret("int:bit_not(0)"), Ok(Value::Integer(-1)));
+    assert_eq!(interpret("int:bit_not(-6)"), Ok(Value::Integer(5)));
+    assert_eq!(interpret("int:bit_and(-1, 255)"), Ok(Value::Integer(255)));
+}
+
+#[test]
+fn pow() {
+    assert_eq!(
+        interpret("int:pow(2, 10)"),
+        Ok(Value::ok(Value::Integer(1024)))
+    );
+    assert_eq!(
+        interpret("int:pow(-1, 3)"),
+        Ok(Value::ok(Value::Integer(-1)))
+    );
+    assert_eq!(
+        interpret("int:pow(-1, 5000000000)"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("int:pow(2, 63)"),
+        Ok(Value::error(Value::string("Integer overflow.")))
+    );
+    assert_eq!(
+        interpret("int:pow(2, -1)"),
+        Ok(Value::error(Value::string(
+            "Cannot raise to the power of -1. Use a non-negative number."
+        )))
+    );
+    assert_eq!(
+        interpret("float:pow(2.0, 0.5)"),
+        Ok(Value::Float(2.0_f64.powf(0.5)))
+    );
+    assert_eq!(interpret("float:pow(2.0, -1.0)"), Ok(Value::Float(0.5)));
+}
@@ -0,0 +1,47 @@
+use dust_lang::{error::RuntimeError, *};
+
+#[test]
+fn parse_and_index() {
+    assert_eq!(
+        interpret(
+            "
+            data = json:parse('{\"name\":\"dust\",\"version\":1}')
+            data:name
+            "
+        ),
+        Ok(Value::string("dust"))
+    );
+    assert_eq!(
+        interpret("json:parse('[1, 2.5, true, null]')"),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(1),
+            Value::Float(2.5),
+            Value::Boolean(true),
+            Value::none(),
+        ])))
+    );
+}
+
+#[test]
+fn round_trip() {
+    assert_eq!(
+        interpret("json:create(json:parse('{\"name\":\"dust\",\"version\":1}'))"),
+        Ok(Value::string("{\"name\":\"dust\",\"version\":1}"))
+    );
+    assert_eq!(
+        interpret("json:create(json:parse('[null]'))"),
+        Ok(Value::string("[null]"))
+    );
+}
+
+#[test]
+fn cannot_serialize() {
+    assert!(matches!(
+        interpret("json:create([() <int> { 1 }])"),
+        Err(Error::Runtime(RuntimeError::CannotSerialize { .. }))
+    ));
+    assert!(matches!(
+        interpret("json:create({ x = str:find('abc', 'a') })"),
+        Err(Error::Runtime(RuntimeError::CannotSerialize { .. }))
+    ));
+}
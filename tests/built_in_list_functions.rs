@@ -0,0 +1,741 @@
+use dust_lang::{
+    error::{RuntimeError, ValidationError},
+    *,
+};
+
+#[test]
+fn first() {
+    assert_eq!(
+        interpret("list:first([1, 2, 3])"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("list:first([1])"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("list:first([])"),
+        Ok(Value::error(Value::string("The list is empty.")))
+    );
+}
+
+#[test]
+fn last() {
+    assert_eq!(
+        interpret("list:last([1, 2, 3])"),
+        Ok(Value::ok(Value::Integer(3)))
+    );
+    assert_eq!(
+        interpret("list:last([1])"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("list:last([])"),
+        Ok(Value::error(Value::string("The list is empty.")))
+    );
+}
+
+#[test]
+fn nth() {
+    assert_eq!(
+        interpret("list:nth([1, 2, 3], 2)"),
+        Ok(Value::ok(Value::Integer(3)))
+    );
+    assert_eq!(
+        interpret("list:nth([1, 2, 3], -1)"),
+        Ok(Value::ok(Value::Integer(3)))
+    );
+    assert_eq!(
+        interpret("list:nth([1, 2, 3], -3)"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("list:nth([1, 2, 3], 3)"),
+        Ok(Value::error(Value::string(
+            "Index 3 is out of bounds for a list of length 3."
+        )))
+    );
+    assert_eq!(
+        interpret("list:nth([], -1)"),
+        Ok(Value::error(Value::string(
+            "Index -1 is out of bounds for a list of length 0."
+        )))
+    );
+}
+
+#[test]
+fn get() {
+    assert_eq!(
+        interpret("list:get([1, 2, 3], 0)"),
+        Ok(Value::some(Value::Integer(1)))
+    );
+    assert_eq!(interpret("list:get([1, 2, 3], 3)"), Ok(Value::none()));
+    assert_eq!(interpret("list:get([1, 2, 3], -1)"), Ok(Value::none()));
+    assert_eq!(interpret("list:get([], 0)"), Ok(Value::none()));
+}
+
+#[test]
+fn type_mismatch() {
+    assert_eq!(
+        interpret("list:first('abc')"),
+        Err(Error::Validation(ValidationError::TypeCheck {
+            expected: Type::list(Type::Any),
+            actual: Type::String,
+            position: SourcePosition {
+                start_byte: 0,
+                end_byte: 17,
+                start_row: 1,
+                start_column: 0,
+                end_row: 1,
+                end_column: 17
+            }
+        }))
+    );
+    assert_eq!(
+        interpret("list:first(['a', 1])"),
+        Ok(Value::ok(Value::string("a")))
+    );
+}
+
+#[test]
+fn sum() {
+    assert_eq!(interpret("list:sum([1, 2, 3])"), Ok(Value::Integer(6)));
+    assert_eq!(interpret("list:sum([])"), Ok(Value::Integer(0)));
+    assert_eq!(
+        interpret("list:sum_float([0.5, 1.5])"),
+        Ok(Value::Float(2.0))
+    );
+    assert_eq!(interpret("list:sum_float([])"), Ok(Value::Float(0.0)));
+    assert!(matches!(
+        interpret("list:sum(['a', 'b'])"),
+        Err(Error::Validation(ValidationError::TypeCheck { .. }))
+    ));
+}
+
+#[test]
+fn min_and_max() {
+    assert_eq!(
+        interpret("list:min([3, 1, 2])"),
+        Ok(Value::ok(Value::Integer(1)))
+    );
+    assert_eq!(
+        interpret("list:max([3, 1, 2])"),
+        Ok(Value::ok(Value::Integer(3)))
+    );
+    assert_eq!(
+        interpret("list:max(['a', 'c', 'b'])"),
+        Ok(Value::ok(Value::string("c")))
+    );
+    assert_eq!(
+        interpret("list:max([])"),
+        Ok(Value::error(Value::string("The list is empty.")))
+    );
+}
+
+#[test]
+fn mean() {
+    assert_eq!(
+        interpret("list:mean([1.0, 2.0, 3.0])"),
+        Ok(Value::ok(Value::Float(2.0)))
+    );
+    assert_eq!(
+        interpret("list:mean([])"),
+        Ok(Value::error(Value::string("The list is empty.")))
+    );
+}
+
+#[test]
+fn reverse() {
+    assert_eq!(
+        interpret(
+            "
+            numbers = [1, 2, 3]
+            reversed = list:reverse(numbers)
+            assert_equal(numbers, [1, 2, 3])
+            reversed
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(3),
+            Value::Integer(2),
+            Value::Integer(1),
+        ])))
+    );
+}
+
+#[test]
+fn reverse_in_place() {
+    assert_eq!(
+        interpret(
+            "
+            numbers = [1, 2, 3]
+            list:reverse_in_place(numbers)
+            numbers
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(3),
+            Value::Integer(2),
+            Value::Integer(1),
+        ])))
+    );
+}
+
+#[test]
+fn copy() {
+    assert_eq!(
+        interpret(
+            "
+            numbers = [1, 2]
+            copy = list:copy(numbers)
+            copy += 3
+            assert_equal(copy, [1, 2, 3])
+            numbers
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(1),
+            Value::Integer(2),
+        ])))
+    );
+}
+
+#[test]
+fn copy_deep() {
+    assert_eq!(
+        interpret(
+            "
+            nested = [[1, 2]]
+            shallow = list:copy(nested)
+            deep = list:copy_deep(nested)
+            list:reverse_in_place(nested:0)
+            assert_equal(shallow:0, [2, 1])
+            deep:0
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(1),
+            Value::Integer(2),
+        ])))
+    );
+}
+
+#[test]
+fn group_by() {
+    let mut groups = Map::new();
+
+    groups.set(
+        Identifier::new("a"),
+        Value::List(List::with_items(vec![
+            Value::string("apple"),
+            Value::string("avocado"),
+        ])),
+    );
+    groups.set(
+        Identifier::new("b"),
+        Value::List(List::with_items(vec![
+            Value::string("banana"),
+            Value::string("blueberry"),
+        ])),
+    );
+
+    assert_eq!(
+        interpret(
+            "
+            list:group_by(
+                ['apple', 'banana', 'avocado', 'blueberry'],
+                (fruit <str>) <str> { str:truncate(fruit, 1) }
+            )
+            "
+        ),
+        Ok(Value::Map(groups))
+    );
+    assert_eq!(
+        interpret("list:group_by([], (item <any>) <str> { 'key' })"),
+        Ok(Value::Map(Map::new()))
+    );
+}
+
+#[test]
+fn group_by_non_string_key() {
+    assert!(matches!(
+        interpret("list:group_by([1, 2], (number <int>) <int> { number })"),
+        Err(Error::Validation(ValidationError::TypeCheck { .. }))
+    ));
+}
+
+#[test]
+fn any_all_and_none() {
+    assert_eq!(
+        interpret("list:any([1, 2, 3], (x <int>) <bool> { x > 2 })"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("list:any([], (x <int>) <bool> { x > 2 })"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("list:all([1, 2, 3], (x <int>) <bool> { x > 0 })"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("list:all([1, 2, 3], (x <int>) <bool> { x > 1 })"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("list:all([], (x <int>) <bool> { x > 0 })"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("list:none([1, 2, 3], (x <int>) <bool> { x > 3 })"),
+        Ok(Value::Boolean(true))
+    );
+}
+
+#[test]
+fn predicates_short_circuit() {
+    assert_eq!(
+        interpret("list:any([true, 'not a boolean'], (x <any>) <bool> { x })"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("list:all([false, 'not a boolean'], (x <any>) <bool> { x })"),
+        Ok(Value::Boolean(false))
+    );
+}
+
+#[test]
+fn product() {
+    assert_eq!(
+        interpret("list:product([1, 2, 3, 4])"),
+        Ok(Value::Integer(24))
+    );
+    assert_eq!(interpret("list:product([])"), Ok(Value::Integer(1)));
+}
+
+#[test]
+fn transpose() {
+    assert_eq!(
+        interpret("list:transpose([[1, 2], [3, 4], [5, 6]])"),
+        interpret("[[1, 3, 5], [2, 4, 6]]")
+    );
+    assert_eq!(
+        interpret("list:transpose([])"),
+        Ok(Value::List(List::new()))
+    );
+    assert_eq!(
+        interpret("list:transpose([[1, 2], [3]])"),
+        Err(Error::Runtime(RuntimeError::ValidationFailure(
+            ValidationError::ExpectedFixedLenList {
+                expected_len: 2,
+                actual: Value::List(List::with_items(vec![Value::Integer(3)]))
+            }
+        )))
+    );
+}
+
+#[test]
+fn zip_with() {
+    assert_eq!(
+        interpret("list:zip_with([1, 2, 3], [4, 5, 6], (a <int>, b <int>) <int> { a + b })"),
+        interpret("[5, 7, 9]")
+    );
+    assert_eq!(
+        interpret("list:zip_with([1, 2, 3], [4], (a <int>, b <int>) <int> { a * b })"),
+        interpret("[4]")
+    );
+}
+
+#[test]
+fn scan_left() {
+    assert_eq!(
+        interpret("list:scan_left([1, 2, 3, 4], 0, (acc <int>, x <int>) <int> { acc + x })"),
+        interpret("[1, 3, 6, 10]")
+    );
+    assert_eq!(
+        interpret("list:scan_left([], 0, (acc <int>, x <int>) <int> { acc + x })"),
+        Ok(Value::List(List::new()))
+    );
+}
+
+#[test]
+fn count() {
+    assert_eq!(
+        interpret("list:count([1, 1, 2, 3], 1)"),
+        Ok(Value::Integer(2))
+    );
+    assert_eq!(interpret("list:count([1, 2], 'a')"), Ok(Value::Integer(0)));
+}
+
+#[test]
+fn count_by() {
+    assert_eq!(
+        interpret("list:count_by([1, 2, 3, 4], (x <int>) <bool> { x % 2 == 0 })"),
+        Ok(Value::Integer(2))
+    );
+}
+
+#[test]
+fn unique() {
+    assert_eq!(
+        interpret("list:unique([1, 2, 1, 3, 2])"),
+        interpret("[1, 2, 3]")
+    );
+}
+
+#[test]
+fn frequency_map() {
+    assert_eq!(
+        interpret("list:frequency_map(['a', 'b', 'a', 'c', 'a'])"),
+        interpret("{ a = 3, b = 1, c = 1 }")
+    );
+}
+
+#[test]
+fn find() {
+    assert_eq!(
+        interpret("list:find([1, 3, 5, 7, 9], (x <int>) <bool> { x > 4 })"),
+        Ok(Value::some(Value::Integer(5)))
+    );
+    assert_eq!(
+        interpret("list:find([1, 3, 5, 7, 9], (x <int>) <bool> { x > 10 })"),
+        Ok(Value::none())
+    );
+}
+
+#[test]
+fn find_index() {
+    assert_eq!(
+        interpret("list:find_index([1, 3, 5, 7, 9], (x <int>) <bool> { x > 4 })"),
+        Ok(Value::Integer(2))
+    );
+    assert_eq!(
+        interpret("list:find_index([1, 3, 5, 7, 9], (x <int>) <bool> { x > 10 })"),
+        Ok(Value::Integer(-1))
+    );
+}
+
+#[test]
+fn find_last() {
+    assert_eq!(
+        interpret("list:find_last([1, 3, 5, 7, 9], (x <int>) <bool> { x > 4 })"),
+        Ok(Value::some(Value::Integer(9)))
+    );
+}
+
+#[test]
+fn position_of() {
+    assert_eq!(
+        interpret("list:position_of([1, 3, 5, 7, 9], 7)"),
+        Ok(Value::Integer(3))
+    );
+    assert_eq!(
+        interpret("list:position_of([1, 3, 5, 7, 9], 4)"),
+        Ok(Value::Integer(-1))
+    );
+}
+
+#[test]
+fn take_while_and_drop_while() {
+    assert_eq!(
+        interpret("list:take_while([2, 4, 6, 1, 3], (x <int>) <bool> { x % 2 == 0 })"),
+        interpret("[2, 4, 6]")
+    );
+    assert_eq!(
+        interpret("list:drop_while([2, 4, 6, 1, 3], (x <int>) <bool> { x % 2 == 0 })"),
+        interpret("[1, 3]")
+    );
+    assert_eq!(
+        interpret("list:take_while([], (x <int>) <bool> { x % 2 == 0 })"),
+        Ok(Value::List(List::new()))
+    );
+    assert_eq!(
+        interpret("list:drop_while([1, 2], (x <int>) <bool> { true })"),
+        Ok(Value::List(List::new()))
+    );
+    assert_eq!(
+        interpret("list:take_while([1, 2], (x <int>) <bool> { false })"),
+        Ok(Value::List(List::new()))
+    );
+}
+
+#[test]
+fn span() {
+    assert_eq!(
+        interpret("list:span([2, 4, 6, 1, 3], (x <int>) <bool> { x % 2 == 0 })"),
+        interpret("[[2, 4, 6], [1, 3]]")
+    );
+    assert_eq!(
+        interpret("list:span([], (x <int>) <bool> { true })"),
+        interpret("[[], []]")
+    );
+}
+
+#[test]
+fn partition() {
+    assert_eq!(
+        interpret("list:partition([2, 4, 6, 1, 3], (x <int>) <bool> { x % 2 == 0 })"),
+        interpret("[[2, 4, 6], [1, 3]]")
+    );
+    assert_eq!(
+        interpret("list:partition([1, 2, 3, 4], (x <int>) <bool> { x % 2 == 0 })"),
+        interpret("[[2, 4], [1, 3]]")
+    );
+    assert_eq!(
+        interpret("list:partition([1, 2], (x <int>) <bool> { true })"),
+        interpret("[[1, 2], []]")
+    );
+}
+
+#[test]
+fn intersperse() {
+    assert_eq!(
+        interpret("list:intersperse([1, 2, 3], 0)"),
+        interpret("[1, 0, 2, 0, 3]")
+    );
+    assert_eq!(interpret("list:intersperse([], 0)"), interpret("[]"));
+    assert_eq!(interpret("list:intersperse([1], 0)"), interpret("[1]"));
+}
+
+#[test]
+fn intersperse_with() {
+    assert_eq!(
+        interpret("list:intersperse_with(['a', 'b', 'c'], () <str> { '-' })"),
+        interpret("['a', '-', 'b', '-', 'c']")
+    );
+    assert_eq!(
+        interpret("list:intersperse_with([], () <int> { 0 })"),
+        interpret("[]")
+    );
+}
+
+#[test]
+fn merge_sorted() {
+    assert_eq!(
+        interpret("list:merge_sorted([1, 3, 5], [2, 4, 6])"),
+        interpret("[1, 2, 3, 4, 5, 6]")
+    );
+    assert_eq!(
+        interpret("list:merge_sorted([], [1, 2])"),
+        interpret("[1, 2]")
+    );
+    assert_eq!(
+        interpret("list:merge_sorted([1, 1, 7], [1, 8])"),
+        interpret("[1, 1, 1, 7, 8]")
+    );
+}
+
+#[test]
+fn sliding() {
+    assert_eq!(
+        interpret("list:sliding([1, 2, 3, 4, 5], 3, 1)"),
+        Ok(Value::ok(
+            interpret("[[1, 2, 3], [2, 3, 4], [3, 4, 5]]").unwrap()
+        ))
+    );
+    assert_eq!(
+        interpret("list:sliding([1, 2, 3, 4, 5], 3, 2)"),
+        Ok(Value::ok(interpret("[[1, 2, 3], [3, 4, 5]]").unwrap()))
+    );
+    assert_eq!(
+        interpret("list:sliding([1, 2, 3, 4, 5, 6, 7], 2, 3)"),
+        Ok(Value::ok(interpret("[[1, 2], [4, 5]]").unwrap()))
+    );
+    assert_eq!(
+        interpret("list:sliding([1, 2], 3, 1)"),
+        Ok(Value::ok(interpret("[]").unwrap()))
+    );
+    assert_eq!(
+        interpret("list:sliding([1, 2], 1, 0)"),
+        Ok(Value::error(Value::string(
+            "The window size and step must be greater than zero."
+        )))
+    );
+}
+
+#[test]
+fn combinations() {
+    assert_eq!(
+        interpret("list:combinations([1, 2, 3], 2)"),
+        Ok(Value::ok(interpret("[[1, 2], [1, 3], [2, 3]]").unwrap()))
+    );
+    assert_eq!(
+        interpret("list:combinations([1, 2], 3)"),
+        Ok(Value::ok(interpret("[]").unwrap()))
+    );
+}
+
+#[test]
+fn permutations() {
+    assert_eq!(
+        interpret("list:permutations([1, 2])"),
+        Ok(Value::ok(interpret("[[1, 2], [2, 1]]").unwrap()))
+    );
+    assert_eq!(
+        interpret("list:permutations([1, 2, 3, 4, 5, 6, 7, 8])"),
+        Ok(Value::error(Value::string(
+            "The result would have more than 10000 lists."
+        )))
+    );
+}
+
+#[test]
+fn cartesian_product() {
+    assert_eq!(
+        interpret("list:cartesian_product([1, 2], ['a', 'b'])"),
+        Ok(Value::ok(
+            interpret("[[1, 'a'], [1, 'b'], [2, 'a'], [2, 'b']]").unwrap()
+        ))
+    );
+}
+
+#[test]
+fn to_map() {
+    assert_eq!(
+        interpret("list:to_map([['a', 1], ['a', 2]])"),
+        Ok(Value::ok(interpret("{ a = 2 }").unwrap()))
+    );
+    assert_eq!(
+        interpret("list:to_map([['a', 1], ['b', 2, 3]])"),
+        Ok(Value::error(Value::string(
+            "Pair 1 does not have exactly two items."
+        )))
+    );
+    assert_eq!(
+        interpret("list:to_map([[1, 'a']])"),
+        Ok(Value::error(Value::string(
+            "The key of pair 0 is not a string."
+        )))
+    );
+}
+
+#[test]
+fn map_indexed() {
+    assert_eq!(
+        interpret(
+            "list:map_indexed(['a', 'b', 'c'], (i <int>, x <str>) <str> {
+                str:sprintf('%d: %s', i, x)
+            })"
+        ),
+        interpret("['0: a', '1: b', '2: c']")
+    );
+}
+
+#[test]
+fn flat_map_indexed() {
+    assert_eq!(
+        interpret(
+            "list:flat_map_indexed(['a', 'b'], (i <int>, x <str>) <[any]> {
+                [i, x]
+            })"
+        ),
+        interpret("[0, 'a', 1, 'b']")
+    );
+}
+
+#[test]
+fn for_each() {
+    assert_eq!(
+        interpret(
+            "
+            rows = [[1, 2], [3, 4]]
+            list:for_each(rows, (row <[int]>) <none> {
+                list:reverse_in_place(row)
+            })
+            rows
+            "
+        ),
+        interpret("[[2, 1], [4, 3]]")
+    );
+    assert_eq!(
+        interpret(
+            "list:for_each_indexed([1, 2], (i <int>, x <int>) <none> {
+                assert_equal(i + 1, x)
+            })"
+        ),
+        Ok(Value::none())
+    );
+}
+
+#[test]
+fn sort() {
+    assert_eq!(interpret("list:sort([3, 1, 2])"), interpret("[1, 2, 3]"));
+    assert_eq!(
+        interpret("list:sort(['b', 'c', 'a'])"),
+        interpret("['a', 'b', 'c']")
+    );
+}
+
+#[test]
+fn flatten() {
+    assert_eq!(
+        interpret("list:flatten([[1, 2], [3]])"),
+        interpret("[1, 2, 3]")
+    );
+    assert_eq!(interpret("list:flatten([[1], 2, []])"), interpret("[1, 2]"));
+}
+
+#[test]
+fn push_and_pop() {
+    assert_eq!(
+        interpret(
+            "
+            numbers = [1, 2]
+            list:push(numbers, 3)
+            numbers
+            "
+        ),
+        interpret("[1, 2, 3]")
+    );
+    assert_eq!(
+        interpret(
+            "
+            numbers = [1, 2]
+            list:pop(numbers)
+            "
+        ),
+        Ok(Value::some(Value::Integer(2)))
+    );
+    assert_eq!(interpret("list:pop([])"), Ok(Value::none()));
+}
+
+#[test]
+fn contains() {
+    assert_eq!(
+        interpret("list:contains([1, 2], 2)"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("list:contains([1, 2], 3)"),
+        Ok(Value::Boolean(false))
+    );
+}
+
+#[test]
+fn slice() {
+    assert_eq!(
+        interpret("list:slice([1, 2, 3, 4], 1, 3)"),
+        interpret("[2, 3]")
+    );
+    assert_eq!(
+        interpret("list:slice([1, 2, 3, 4], -2, 10)"),
+        interpret("[3, 4]")
+    );
+    assert_eq!(interpret("list:slice([1, 2, 3], 2, 1)"), interpret("[]"));
+}
+
+#[test]
+fn zip() {
+    assert_eq!(
+        interpret("list:zip([1, 2, 3], ['a', 'b'])"),
+        interpret("[[1, 'a'], [2, 'b']]")
+    );
+}
+
+#[test]
+fn dedup() {
+    assert_eq!(
+        interpret("list:dedup([1, 1, 2, 1, 3, 3])"),
+        interpret("[1, 2, 1, 3]")
+    );
+}
@@ -0,0 +1,325 @@
+use dust_lang::{
+    error::{RuntimeError, ValidationError},
+    *,
+};
+
+#[test]
+fn of() {
+    let mut map = Map::new();
+
+    map.set(Identifier::new("x"), Value::Integer(1));
+    map.set(Identifier::new("y"), Value::Integer(2));
+
+    assert_eq!(interpret("map:of('x', 1, 'y', 2)"), Ok(Value::Map(map)));
+    assert_eq!(interpret("map:of()"), Ok(Value::Map(Map::new())));
+}
+
+#[test]
+fn of_equals_map_literal() {
+    assert_eq!(
+        interpret("map:of('x', 1, 'y', 'two') == { x = 1, y = 'two' }"),
+        Ok(Value::Boolean(true))
+    );
+}
+
+#[test]
+fn of_odd_argument_amount() {
+    assert_eq!(
+        interpret("map:of('x', 1, 'y')"),
+        Err(Error::Runtime(RuntimeError::ExpectedEvenArgumentAmount {
+            function_name: "of".to_string(),
+            actual: 3
+        }))
+    );
+}
+
+#[test]
+fn of_non_string_key() {
+    assert_eq!(
+        interpret("map:of(1, 2)"),
+        Err(Error::Runtime(RuntimeError::ValidationFailure(
+            ValidationError::ExpectedString {
+                actual: Value::Integer(1)
+            }
+        )))
+    );
+}
+
+#[test]
+fn count_and_is_empty() {
+    assert_eq!(
+        interpret("map:count({ x = 1, y = 2 })"),
+        Ok(Value::Integer(2))
+    );
+    assert_eq!(interpret("map:count(map:of())"), Ok(Value::Integer(0)));
+    assert_eq!(
+        interpret("map:is_empty({ x = 1 })"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("map:is_empty(map:of())"),
+        Ok(Value::Boolean(true))
+    );
+}
+
+#[test]
+fn get() {
+    assert_eq!(
+        interpret("map:get({ x = 1 }, 'x')"),
+        Ok(Value::some(Value::Integer(1)))
+    );
+    assert_eq!(interpret("map:get({ x = 1 }, 'y')"), Ok(Value::none()));
+}
+
+#[test]
+fn get_or() {
+    assert_eq!(
+        interpret("map:get_or({ x = 1 }, 'x', 0)"),
+        Ok(Value::Integer(1))
+    );
+    assert_eq!(
+        interpret("map:get_or({ x = 1 }, 'y', 0)"),
+        Ok(Value::Integer(0))
+    );
+}
+
+#[test]
+fn set() {
+    assert_eq!(
+        interpret(
+            "
+            data = { x = 1 }
+            data = map:set(data, 'x', 2)
+            data:x
+            "
+        ),
+        Ok(Value::Integer(2))
+    );
+    assert_eq!(
+        interpret(
+            "
+            data = { x = 1 }
+            map:set(data, 'y', 2)
+            map:count(data)
+            "
+        ),
+        Ok(Value::Integer(1))
+    );
+}
+
+#[test]
+fn keys_and_values() {
+    assert_eq!(
+        interpret("map:keys({ b = 2, a = 1 })"),
+        Ok(Value::List(List::with_items(vec![
+            Value::string("a"),
+            Value::string("b"),
+        ])))
+    );
+    assert_eq!(
+        interpret("map:values({ b = 2, a = 1 })"),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(1),
+            Value::Integer(2),
+        ])))
+    );
+}
+
+#[test]
+fn remove() {
+    assert_eq!(
+        interpret("map:remove({ x = 1, y = 2 }, 'x')"),
+        interpret("{ y = 2 }")
+    );
+}
+
+#[test]
+fn ordered_keys_keep_insertion_order() {
+    assert_eq!(
+        interpret(
+            "
+            ordered = map:ordered_new('b', 2, 'c', 3)
+            ordered = map:set(ordered, 'a', 1)
+            map:keys(ordered)
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::string("b"),
+            Value::string("c"),
+            Value::string("a"),
+        ])))
+    );
+}
+
+#[test]
+fn ordered_get_and_remove() {
+    assert_eq!(
+        interpret(
+            "
+            ordered = map:ordered_new('x', 1, 'y', 2, 'z', 3)
+            ordered = map:remove(ordered, 'y')
+
+            assert_equal(map:get_or(ordered, 'y', 0), 0)
+            assert_equal(map:count(ordered), 2)
+            assert_equal(ordered:z, 3)
+            map:values(ordered)
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(1),
+            Value::Integer(3),
+        ])))
+    );
+}
+
+#[test]
+fn set_and_remove_leave_the_original() {
+    for constructor in ["map:of", "map:ordered_new"] {
+        assert_eq!(
+            interpret(&format!(
+                "
+                original = {constructor}('x', 1, 'y', 2)
+                changed = map:set(original, 'x', 3)
+                changed = map:remove(changed, 'y')
+
+                assert_equal(map:count(changed), 1)
+                assert_equal(changed:x, 3)
+                [map:count(original), original:x, original:y]
+                "
+            )),
+            Ok(Value::List(List::with_items(vec![
+                Value::Integer(2),
+                Value::Integer(1),
+                Value::Integer(2),
+            ])))
+        );
+    }
+}
+
+#[test]
+fn ordered_display() {
+    assert_eq!(
+        interpret("cast:str(map:ordered_new('b', 2, 'a', 'one'))"),
+        Ok(Value::ok(Value::string("{b: 2, a: one}")))
+    );
+}
+
+#[test]
+fn zip() {
+    assert_eq!(
+        interpret("map:zip({ a = 1, b = 2 }, { b = 3, c = 4 })"),
+        interpret("{ b = [2, 3] }")
+    );
+}
+
+#[test]
+fn zip_all() {
+    let mut map = Map::new();
+
+    map.set(
+        Identifier::new("a"),
+        Value::List(List::with_items(vec![Value::Integer(1), Value::none()])),
+    );
+    map.set(
+        Identifier::new("b"),
+        Value::List(List::with_items(vec![Value::Integer(2), Value::Integer(3)])),
+    );
+    map.set(
+        Identifier::new("c"),
+        Value::List(List::with_items(vec![Value::none(), Value::Integer(4)])),
+    );
+
+    assert_eq!(
+        interpret(
+            "
+            missing = map:get({ x = 1 }, 'y')
+            map:zip_all({ a = 1, b = 2 }, { b = 3, c = 4 }, missing)
+            "
+        ),
+        Ok(Value::Map(map))
+    );
+}
+
+#[test]
+fn unzip() {
+    assert_eq!(
+        interpret(
+            "
+            zipped = map:zip({ a = 1, b = 2 }, { a = 'one', b = 'two' })
+            map:unzip(zipped)
+            "
+        ),
+        interpret("[{ a = 1, b = 2 }, { a = 'one', b = 'two' }]")
+    );
+    assert!(interpret("map:unzip({ a = [1, 2, 3] })").is_err());
+}
+
+#[test]
+fn flat_map() {
+    assert_eq!(
+        interpret(
+            "
+            map:flat_map({ a = 1 }, (key <str>, value <int>) <map> {
+                map:of(key + '_2', value * 2)
+            })
+            "
+        ),
+        interpret("{ a_2 = 2 }")
+    );
+    assert_eq!(
+        interpret(
+            "
+            map:flat_map({ a = 1, b = 2 }, (key <str>, value <int>) <map> {
+                map:of('total', value)
+            })
+            "
+        ),
+        interpret("{ total = 2 }")
+    );
+}
+
+#[test]
+fn flat_map_list() {
+    assert_eq!(
+        interpret(
+            "
+            map:flat_map_list({ a = 1, b = 2 }, (key <str>, value <int>) <[any]> {
+                [key, value]
+            })
+            "
+        ),
+        interpret("['a', 1, 'b', 2]")
+    );
+}
+
+#[test]
+fn group_by_key() {
+    assert_eq!(
+        interpret("map:group_by_key([['x', 1], ['y', 2], ['x', 3]])"),
+        interpret("{ x = [1, 3], y = [2] }")
+    );
+}
+
+#[test]
+fn to_list() {
+    assert_eq!(
+        interpret("map:to_list({ b = 2, a = 1 })"),
+        interpret("[['a', 1], ['b', 2]]")
+    );
+    assert_eq!(
+        interpret("map:to_list(map:ordered_new('b', 2, 'a', 1))"),
+        interpret("[['a', 1], ['b', 2]]")
+    );
+    assert_eq!(
+        interpret("list:to_map(map:to_list({ b = 2, a = 1 }))"),
+        Ok(Value::ok(interpret("{ a = 1, b = 2 }").unwrap()))
+    );
+}
+
+#[test]
+fn to_entries() {
+    assert_eq!(
+        interpret("map:to_entries({ x = 1 })"),
+        interpret("[{ key = 'x', value = 1 }]")
+    );
+}
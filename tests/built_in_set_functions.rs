@@ -0,0 +1,82 @@
+use dust_lang::{error::ValidationError, *};
+
+#[test]
+fn add_and_contains() {
+    assert_eq!(
+        interpret(
+            "
+            numbers = set:new()
+            set:add(numbers, 1)
+            set:add(numbers, 2)
+            set:add(numbers, 2)
+
+            assert_equal(set:contains(numbers, 2), true)
+            assert_equal(set:contains(numbers, 3), false)
+            set:to_list(numbers)
+            "
+        ),
+        interpret("[1, 2]")
+    );
+}
+
+#[test]
+fn remove() {
+    assert_eq!(
+        interpret(
+            "
+            numbers = set:from_list([1, 2, 3])
+
+            assert_equal(set:remove(numbers, 2), true)
+            assert_equal(set:remove(numbers, 2), false)
+            set:to_list(numbers)
+            "
+        ),
+        interpret("[1, 3]")
+    );
+}
+
+#[test]
+fn from_list_removes_duplicates() {
+    assert_eq!(
+        interpret("length(set:to_list(set:from_list([1, 2, 2, 3])))"),
+        Ok(Value::Integer(3))
+    );
+    assert_eq!(
+        interpret("set:to_list(set:from_list([3, 1, 2, 1]))"),
+        interpret("[1, 2, 3]")
+    );
+}
+
+#[test]
+fn union() {
+    assert_eq!(
+        interpret("set:to_list(set:union(set:from_list([1, 2]), set:from_list([2, 3])))"),
+        interpret("[1, 2, 3]")
+    );
+}
+
+#[test]
+fn intersection() {
+    assert_eq!(
+        interpret("set:to_list(set:intersection(set:from_list([1, 2]), set:from_list([2, 3])))"),
+        interpret("[2]")
+    );
+}
+
+#[test]
+fn difference() {
+    assert_eq!(
+        interpret("set:to_list(set:difference(set:from_list([1, 2]), set:from_list([2, 3])))"),
+        interpret("[1]")
+    );
+}
+
+#[test]
+fn expected_set_report() {
+    let source = "set:to_list(numbers)";
+    let error = ValidationError::ExpectedSet {
+        actual: Value::Integer(1),
+    };
+
+    assert!(error.create_report(source).contains("Expected a set"));
+}
@@ -1,4 +1,7 @@
-use dust_lang::{interpret, List, Value};
+use dust_lang::{
+    error::{RuntimeError, ValidationError},
+    interpret, Error, List, Value,
+};
 
 #[test]
 fn as_bytes() {
@@ -47,3 +50,504 @@ fn insert() {
         Ok(Value::String("foobar".to_string()))
     );
 }
+
+#[test]
+fn lines() {
+    let result = interpret("str:lines('one\r\ntwo\n\nthree\n')");
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::string("one"),
+            Value::string("two"),
+            Value::string(""),
+            Value::string("three"),
+        ])))
+    );
+
+    let result = interpret("str:lines('\n\n')");
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::string(""),
+            Value::string(""),
+        ])))
+    );
+
+    let result = interpret("str:lines('')");
+
+    assert_eq!(result, Ok(Value::List(List::new())));
+}
+
+#[test]
+fn words() {
+    let result = interpret("str:words('  one two\t three\n')");
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::string("one"),
+            Value::string("two"),
+            Value::string("three"),
+        ])))
+    );
+
+    let result = interpret("str:words('')");
+
+    assert_eq!(result, Ok(Value::List(List::new())));
+}
+
+#[test]
+fn split_at() {
+    let result = interpret("str:split_at('abc', 0)");
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::string(""),
+            Value::string("abc"),
+        ])))
+    );
+
+    let result = interpret("str:split_at('abc', 3)");
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::string("abc"),
+            Value::string(""),
+        ])))
+    );
+
+    let result = interpret("str:split_at('', 0)");
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::string(""),
+            Value::string(""),
+        ])))
+    );
+}
+
+#[test]
+fn to_uppercase() {
+    let result = interpret("str:to_uppercase('hello')");
+
+    assert_eq!(result, Ok(Value::string("HELLO")));
+
+    let result = interpret("str:to_uppercase('straße')");
+
+    assert_eq!(result, Ok(Value::string("STRASSE")));
+}
+
+#[test]
+fn to_ascii_uppercase() {
+    let result = interpret("str:to_ascii_uppercase('hello')");
+
+    assert_eq!(result, Ok(Value::string("HELLO")));
+
+    let result = interpret("str:to_ascii_uppercase('straße')");
+
+    assert_eq!(result, Ok(Value::string("STRAßE")));
+}
+
+#[test]
+fn to_ascii_lowercase() {
+    let result = interpret("str:to_ascii_lowercase('HELLO')");
+
+    assert_eq!(result, Ok(Value::string("hello")));
+}
+
+#[test]
+fn is_uppercase() {
+    let result = interpret("str:is_uppercase('HELLO')");
+
+    assert_eq!(result, Ok(Value::Boolean(true)));
+
+    let result = interpret("str:is_uppercase('Hello')");
+
+    assert_eq!(result, Ok(Value::Boolean(false)));
+}
+
+#[test]
+fn is_lowercase() {
+    let result = interpret("str:is_lowercase('hello 42')");
+
+    assert_eq!(result, Ok(Value::Boolean(true)));
+
+    let result = interpret("str:is_lowercase('Hello')");
+
+    assert_eq!(result, Ok(Value::Boolean(false)));
+}
+
+#[test]
+fn index_of() {
+    let result = interpret("str:index_of('hello', 'l')");
+
+    assert_eq!(result, Ok(Value::Integer(2)));
+
+    let result = interpret("str:index_of('naïve café', 'café')");
+
+    assert_eq!(result, Ok(Value::Integer(7)));
+
+    let result = interpret("str:index_of('hello', 'z')");
+
+    assert_eq!(result, Ok(Value::Integer(-1)));
+
+    let result = interpret("str:index_of('hello', '')");
+
+    assert_eq!(result, Ok(Value::Integer(0)));
+}
+
+#[test]
+fn last_index_of() {
+    let result = interpret("str:last_index_of('hello', 'l')");
+
+    assert_eq!(result, Ok(Value::Integer(3)));
+
+    let result = interpret("str:last_index_of('hello', 'z')");
+
+    assert_eq!(result, Ok(Value::Integer(-1)));
+}
+
+#[test]
+fn index_of_char() {
+    let result = interpret("str:index_of_char('héllo', 'l')");
+
+    assert_eq!(result, Ok(Value::Integer(3)));
+
+    let source = "str:index_of_char('hello', 'll')";
+    let result = interpret(source);
+
+    assert_eq!(
+        result,
+        Err(Error::Runtime(RuntimeError::ValidationFailure(
+            ValidationError::ExpectedCharacter {
+                actual: Value::string("ll")
+            }
+        )))
+    );
+    assert!(result
+        .unwrap_err()
+        .create_report(source)
+        .contains("Expected a single character"));
+}
+
+#[test]
+fn sprintf() {
+    let result = interpret("str:sprintf('%d + %d = %d', 1, 2, 3)");
+
+    assert_eq!(result, Ok(Value::string("1 + 2 = 3")));
+
+    let result = interpret("str:sprintf('%08.2f', 3.14159)");
+
+    assert_eq!(result, Ok(Value::string("00003.14")));
+
+    let result = interpret("str:sprintf('100%%')");
+
+    assert_eq!(result, Ok(Value::string("100%")));
+
+    let result = interpret("str:sprintf('[%5s|%-5s|%x|%o|%05d]', 'ab', 'cd', 255, 8, -42)");
+
+    assert_eq!(result, Ok(Value::string("[   ab|cd   |ff|10|-0042]")));
+}
+
+#[test]
+fn sprintf_errors() {
+    let result = interpret("str:sprintf('%d and %d', 1)");
+
+    assert_eq!(
+        result,
+        Err(Error::Runtime(
+            RuntimeError::ExpectedBuiltInFunctionArgumentAmount {
+                function_name: "sprintf".to_string(),
+                expected: 3,
+                actual: 2
+            }
+        ))
+    );
+
+    let result = interpret("str:sprintf('%d', 'one')");
+
+    assert_eq!(
+        result,
+        Err(Error::Runtime(RuntimeError::ValidationFailure(
+            ValidationError::ExpectedInteger {
+                actual: Value::string("one")
+            }
+        )))
+    );
+}
+
+#[test]
+fn slice() {
+    assert_eq!(
+        interpret("str:slice('héllo', 0, 3)"),
+        Ok(Value::string("hél"))
+    );
+    assert_eq!(
+        interpret("str:slice('hello', -3, -1)"),
+        Ok(Value::string("ll"))
+    );
+    assert_eq!(
+        interpret("str:slice('hello', 2, 100)"),
+        Ok(Value::string("llo"))
+    );
+    assert_eq!(interpret("str:slice('hello', 4, 1)"), Ok(Value::string("")));
+}
+
+#[test]
+fn slice_bytes() {
+    assert_eq!(
+        interpret("str:slice_bytes('hello', 1, 4)"),
+        Ok(Value::string("ell"))
+    );
+    assert_eq!(
+        interpret("str:slice_bytes('héllo', 0, 2)"),
+        Err(Error::Runtime(RuntimeError::InvalidCharBoundary {
+            index: 2
+        }))
+    );
+}
+
+#[test]
+fn compare() {
+    assert_eq!(interpret("str:compare('a', 'b')"), Ok(Value::Integer(-1)));
+    assert_eq!(interpret("str:compare('b', 'a')"), Ok(Value::Integer(1)));
+    assert_eq!(interpret("str:compare('a', 'a')"), Ok(Value::Integer(0)));
+}
+
+#[test]
+fn compare_ignore_case() {
+    assert_eq!(
+        interpret("str:compare_ignore_case('Hello', 'hello')"),
+        Ok(Value::Integer(0))
+    );
+    assert_eq!(
+        interpret("str:compare_ignore_case('apple', 'Banana')"),
+        Ok(Value::Integer(-1))
+    );
+    assert_eq!(
+        interpret("str:equals_ignore_case('HeLLo', 'hello')"),
+        Ok(Value::Boolean(true))
+    );
+}
+
+#[test]
+fn compare_composed_and_decomposed() {
+    let source = "str:compare('\u{e9}', 'e\u{301}')";
+
+    assert_eq!(interpret(source), Ok(Value::Integer(0)));
+
+    let source = "str:compare_bytes('\u{e9}', 'e\u{301}')";
+
+    assert_eq!(interpret(source), Ok(Value::Integer(1)));
+}
+
+#[test]
+fn character_classes() {
+    assert_eq!(interpret("str:is_numeric('123')"), Ok(Value::Boolean(true)));
+    assert_eq!(
+        interpret("str:is_numeric('12.3')"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(interpret("str:is_alpha('héllo')"), Ok(Value::Boolean(true)));
+    assert_eq!(
+        interpret("str:is_alpha('hello!')"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("str:is_alphanumeric('abc123')"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("str:is_alphanumeric('abc 123')"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("str:is_whitespace(' \t')"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("str:is_whitespace(' a ')"),
+        Ok(Value::Boolean(false))
+    );
+}
+
+#[test]
+fn character_classes_of_empty_string() {
+    for function in ["is_numeric", "is_alpha", "is_alphanumeric", "is_whitespace"] {
+        assert_eq!(
+            interpret(&format!("str:{function}('')")),
+            Ok(Value::Boolean(false))
+        );
+    }
+
+    assert_eq!(interpret("str:is_empty('')"), Ok(Value::Boolean(true)));
+}
+
+#[test]
+fn builder() {
+    let pushes = "str:builder_push(builder, 'x')\n".repeat(10000);
+    let source = format!(
+        "
+        builder = str:builder_new()
+        {pushes}
+        assert_equal(str:builder_len(builder), 10000)
+        length(str:builder_finish(builder))
+        "
+    );
+
+    assert_eq!(interpret(&source), Ok(Value::Integer(10000)));
+}
+
+#[test]
+fn builders_are_shared_by_copies_only() {
+    assert_eq!(
+        interpret(
+            "
+            first = str:builder_new()
+            second = str:builder_new()
+            copy = first
+
+            str:builder_push(copy, 'a')
+            str:builder_push(second, 'b')
+
+            [str:builder_finish(first) str:builder_finish(second)]
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::string("a"),
+            Value::string("b"),
+        ])))
+    );
+}
+
+#[test]
+fn builder_finish_twice() {
+    assert_eq!(
+        interpret(
+            "
+            builder = str:builder_new()
+
+            str:builder_push(builder, 'hello')
+            str:builder_push_char(builder, '!')
+
+            assert_equal(str:builder_finish(builder), 'hello!')
+
+            str:builder_finish(builder)
+            "
+        ),
+        Ok(Value::string(""))
+    );
+}
+
+#[test]
+fn unicode_normalization() {
+    assert_eq!(
+        interpret("str:nfc('e\u{301}')"),
+        Ok(Value::string("\u{e9}"))
+    );
+    assert_eq!(
+        interpret("str:nfc(str:nfc('e\u{301}'))"),
+        Ok(Value::string("\u{e9}"))
+    );
+    assert_eq!(
+        interpret("str:nfd('\u{e9}')"),
+        Ok(Value::string("e\u{301}"))
+    );
+    assert_eq!(interpret("str:nfkc('\u{fb01}')"), Ok(Value::string("fi")));
+    assert_eq!(interpret("str:nfkd('\u{2460}')"), Ok(Value::string("1")));
+    assert_eq!(interpret("str:is_nfc('\u{e9}')"), Ok(Value::Boolean(true)));
+    assert_eq!(
+        interpret("str:is_nfc('e\u{301}')"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("str:is_nfd('e\u{301}')"),
+        Ok(Value::Boolean(true))
+    );
+}
+
+#[test]
+fn split_once() {
+    assert_eq!(
+        interpret("str:split_once('a:b:c', ':')"),
+        Ok(Value::ok(interpret("['a', 'b:c']").unwrap()))
+    );
+    assert_eq!(
+        interpret("str:rsplit_once('a:b:c', ':')"),
+        Ok(Value::ok(interpret("['a:b', 'c']").unwrap()))
+    );
+    assert_eq!(
+        interpret("str:split_once('abc', ':')"),
+        Ok(Value::error(Value::string(
+            "The pattern \":\" was not found."
+        )))
+    );
+}
+
+#[test]
+fn split_n() {
+    assert_eq!(
+        interpret("str:split_n('a,b,c,d', 2, ',')"),
+        interpret("['a', 'b,c,d']")
+    );
+    assert_eq!(
+        interpret("str:split_n('a,b,c,d', 1, ',')"),
+        interpret("['a,b,c,d']")
+    );
+}
+
+#[test]
+fn contains() {
+    assert_eq!(
+        interpret("str:contains('foobar', 'oba')"),
+        Ok(Value::Boolean(true))
+    );
+    assert_eq!(
+        interpret("str:contains('foobar', 'baz')"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("str:contains('häagen', 'äa')"),
+        Ok(Value::Boolean(true))
+    );
+}
+
+#[test]
+fn join() {
+    assert_eq!(
+        interpret("str:join(['a', 'b', 'c'], ', ')"),
+        Ok(Value::string("a, b, c"))
+    );
+    assert_eq!(
+        interpret("str:join(['ü', 'ß'], '→')"),
+        Ok(Value::string("ü→ß"))
+    );
+    assert_eq!(interpret("str:join([], ', ')"), Ok(Value::string("")));
+}
+
+#[test]
+fn repeat() {
+    assert_eq!(
+        interpret("str:repeat('ab', 3)"),
+        Ok(Value::string("ababab"))
+    );
+    assert_eq!(interpret("str:repeat('é', 2)"), Ok(Value::string("éé")));
+    assert_eq!(interpret("str:repeat('ab', 0)"), Ok(Value::string("")));
+}
+
+#[test]
+fn replace() {
+    assert_eq!(
+        interpret("str:replace('a-b-c', '-', '+')"),
+        Ok(Value::string("a+b+c"))
+    );
+    assert_eq!(
+        interpret("str:replace('café café', 'é', 'e')"),
+        Ok(Value::string("cafe cafe"))
+    );
+}
@@ -0,0 +1,33 @@
+use std::env;
+
+use dust_lang::*;
+
+// The colored crate reads the environment once, so every case that depends
+// on it is checked in a single test.
+#[test]
+fn styles_follow_environment() {
+    env::set_var("CLICOLOR_FORCE", "1");
+    env::remove_var("NO_COLOR");
+    env::set_var("TERM", "xterm-256color");
+
+    assert_eq!(
+        interpret("term:red('hi')"),
+        Ok(Value::string("\x1b[31mhi\x1b[0m"))
+    );
+    assert_eq!(
+        interpret("term:bold('hi')"),
+        Ok(Value::string("\x1b[1mhi\x1b[0m"))
+    );
+    assert_eq!(
+        interpret("term:color('hi', 255, 128, 0)"),
+        Ok(Value::string("\x1b[38;2;255;128;0mhi\x1b[0m"))
+    );
+
+    env::set_var("TERM", "dumb");
+
+    assert_eq!(interpret("term:red('hi')"), Ok(Value::string("hi")));
+    assert_eq!(
+        interpret("term:color('hi', 255, 128, 0)"),
+        Ok(Value::string("hi"))
+    );
+}
@@ -0,0 +1,65 @@
+use dust_lang::*;
+
+#[test]
+fn encode() {
+    assert_eq!(
+        interpret("url:encode('hello world')"),
+        Ok(Value::string("hello%20world"))
+    );
+    assert_eq!(
+        interpret("url:encode('/search?q=a+b&c')"),
+        Ok(Value::string("/search?q%3Da%2Bb%26c"))
+    );
+    assert_eq!(
+        interpret("url:encode('héllo-_.~')"),
+        Ok(Value::string("h%C3%A9llo-_.~"))
+    );
+}
+
+#[test]
+fn encode_component() {
+    assert_eq!(
+        interpret("url:encode_component('a/b?c')"),
+        Ok(Value::string("a%2Fb%3Fc"))
+    );
+}
+
+#[test]
+fn decode() {
+    assert_eq!(
+        interpret("url:decode('%41')"),
+        Ok(Value::ok(Value::string("A")))
+    );
+    assert_eq!(
+        interpret("url:decode('%GG')"),
+        Ok(Value::error(Value::string(
+            "Invalid percent-encoded sequence at byte 0."
+        )))
+    );
+    assert_eq!(
+        interpret("url:decode('%FF')"),
+        Ok(Value::error(Value::string(
+            "The decoded bytes are not valid UTF-8."
+        )))
+    );
+}
+
+#[test]
+fn decode_lossy() {
+    assert_eq!(
+        interpret("url:decode_lossy('a%GGb%4')"),
+        Ok(Value::string("a?b?"))
+    );
+    assert_eq!(
+        interpret("url:decode_lossy('%FFok')"),
+        Ok(Value::string("?ok"))
+    );
+}
+
+#[test]
+fn round_trip() {
+    assert_eq!(
+        interpret("url:decode(url:encode_component('a+b & c/d?é'))"),
+        Ok(Value::ok(Value::string("a+b & c/d?é")))
+    );
+}
@@ -29,3 +29,79 @@ fn assert_equal() {
         }))
     );
 }
+
+#[test]
+fn assert() {
+    assert_eq!(interpret("assert(1 == 1)"), Ok(Value::none()));
+    assert_eq!(interpret("assert(true, 'unused')"), Ok(Value::none()));
+    assert!(matches!(
+        interpret("assert(1 == 2)"),
+        Err(Error::Runtime(RuntimeError::AssertFailed {
+            message,
+            position: Some(_),
+        })) if message == "assertion failed"
+    ));
+    assert!(matches!(
+        interpret("x = 3\nassert(x < 2, 'x is too big')"),
+        Err(Error::Runtime(RuntimeError::AssertFailed {
+            message,
+            position: Some(position),
+        })) if message == "x is too big" && position.start_row == 2
+    ));
+    assert_eq!(
+        interpret("assert(true, 'a', 'b')"),
+        Err(Error::Runtime(
+            RuntimeError::ExpectedBuiltInFunctionArgumentRange {
+                function_name: "assert".to_string(),
+                minimum: 1,
+                maximum: 2,
+                actual: 3,
+            }
+        ))
+    );
+}
+
+#[test]
+fn panic() {
+    assert_eq!(
+        interpret("panic('something went wrong')"),
+        Err(Error::Runtime(RuntimeError::Panic {
+            message: "something went wrong".to_string(),
+            position: Some(SourcePosition {
+                start_byte: 0,
+                end_byte: 29,
+                start_row: 1,
+                start_column: 0,
+                end_row: 1,
+                end_column: 29,
+            }),
+        }))
+    );
+    assert!(matches!(
+        interpret("panic()"),
+        Err(Error::Runtime(RuntimeError::Panic { message, .. })) if message == "explicit panic"
+    ));
+    assert_eq!(
+        interpret("panic('a', 'b')"),
+        Err(Error::Runtime(
+            RuntimeError::ExpectedBuiltInFunctionArgumentRange {
+                function_name: "panic".to_string(),
+                minimum: 0,
+                maximum: 1,
+                actual: 2,
+            }
+        ))
+    );
+}
+
+#[test]
+fn panic_if() {
+    assert_eq!(interpret("panic_if(false, 'x')"), Ok(Value::none()));
+    assert!(matches!(
+        interpret("x = 1\npanic_if(x == 1, 'x is one')"),
+        Err(Error::Runtime(RuntimeError::Panic {
+            message,
+            position: Some(position),
+        })) if message == "x is one" && position.start_row == 2
+    ));
+}
@@ -97,3 +97,69 @@ fn r#break() {
         result
     );
 }
+
+#[test]
+fn string_for_loop() {
+    let result = interpret(
+        "
+        chars = []
+        for char in 'abc' { chars += char }
+        chars
+        ",
+    );
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::string("a"),
+            Value::string("b"),
+            Value::string("c"),
+        ]))),
+    );
+}
+
+#[test]
+fn map_for_loop() {
+    let result = interpret(
+        "
+        entries = []
+        for entry in { b = 2, a = 1 } { entries += entry }
+        entries
+        ",
+    );
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::List(List::with_items(vec![
+                Value::string("a"),
+                Value::Integer(1)
+            ])),
+            Value::List(List::with_items(vec![
+                Value::string("b"),
+                Value::Integer(2)
+            ])),
+        ]))),
+    );
+}
+
+#[test]
+fn typed_list_for_loop() {
+    let result = interpret(
+        "
+        doubled = []
+        numbers <[int]> = [1 2 3]
+        for number in numbers { doubled += number * 2 }
+        doubled
+        ",
+    );
+
+    assert_eq!(
+        result,
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(2),
+            Value::Integer(4),
+            Value::Integer(6),
+        ]))),
+    );
+}
@@ -47,18 +47,17 @@ fn built_in_function_call() {
 }
 
 #[test]
-fn function_context_does_not_capture_normal_values() {
+fn function_context_captures_normal_values() {
     assert_eq!(
         interpret(
             "
             x = 1
 
             foo = () <any> { x }
+            foo()
             "
         ),
-        Err(Error::Validation(
-            ValidationError::VariableIdentifierNotFound(Identifier::new("x"))
-        ))
+        Ok(Value::Integer(1))
     );
 
     assert_eq!(
@@ -73,6 +72,36 @@ fn function_context_does_not_capture_normal_values() {
     );
 }
 
+#[test]
+fn closures_capture_values_when_created() {
+    assert_eq!(
+        interpret(
+            "
+            make_adder = (amount <int>) <(int) -> int> {
+                (x <int>) <int> { x + amount }
+            }
+            add_two = make_adder(2)
+            add_two(40)
+            "
+        ),
+        Ok(Value::Integer(42))
+    );
+}
+
+#[test]
+fn closures_can_be_passed_as_arguments() {
+    assert_eq!(
+        interpret(
+            "
+            apply = (function <(int) -> int>, x <int>) <int> { function(x) }
+            offset = 10
+            apply((x <int>) <int> { x + offset }, 1)
+            "
+        ),
+        Ok(Value::Integer(11))
+    );
+}
+
 #[test]
 fn function_context_captures_functions() {
     assert_eq!(
@@ -6,3 +6,40 @@ fn complex_logic_sequence() {
 
     assert_eq!(Ok(Value::Boolean(true)), result);
 }
+
+#[test]
+fn nan_comparisons() {
+    assert_eq!(interpret("NaN == NaN"), Ok(Value::Boolean(false)));
+    assert_eq!(interpret("NaN != NaN"), Ok(Value::Boolean(true)));
+    assert_eq!(interpret("NaN < 1.0"), Ok(Value::Boolean(false)));
+    assert_eq!(interpret("NaN > 1.0"), Ok(Value::Boolean(false)));
+    assert_eq!(interpret("NaN >= NaN"), Ok(Value::Boolean(false)));
+    assert_eq!(interpret("1 <= NaN"), Ok(Value::Boolean(false)));
+}
+
+#[test]
+fn infinity_comparisons() {
+    assert_eq!(interpret("Infinity > 1.0e300"), Ok(Value::Boolean(true)));
+    assert_eq!(interpret("1.0 / 0.0 == Infinity"), Ok(Value::Boolean(true)));
+    assert_eq!(interpret("-1.0 / 0.0 < -1.0e300"), Ok(Value::Boolean(true)));
+}
+
+#[test]
+fn and_or_short_circuit() {
+    assert_eq!(
+        interpret("false && panic('evaluated')"),
+        Ok(Value::Boolean(false))
+    );
+    assert_eq!(
+        interpret("true || panic('evaluated')"),
+        Ok(Value::Boolean(true))
+    );
+    assert!(matches!(
+        interpret("true && panic('evaluated')"),
+        Err(Error::Runtime(error::RuntimeError::Panic { .. }))
+    ));
+    assert!(matches!(
+        interpret("false || panic('evaluated')"),
+        Err(Error::Runtime(error::RuntimeError::Panic { .. }))
+    ));
+}
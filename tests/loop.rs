@@ -0,0 +1,91 @@
+use dust_lang::*;
+
+#[test]
+fn loop_with_break() {
+    assert_eq!(interpret("loop { break 1 }"), Ok(Value::Integer(1)));
+}
+
+#[test]
+fn loop_with_block() {
+    assert_eq!(
+        interpret("loop { { 'ignored' } break { 1 + 1 } }"),
+        Ok(Value::Integer(2))
+    );
+}
+
+#[test]
+fn nested_loops() {
+    assert_eq!(
+        interpret("loop { break loop { break 'inner' } }"),
+        Ok(Value::string("inner"))
+    );
+}
+
+#[test]
+fn break_inside_if() {
+    assert_eq!(
+        interpret(
+            "
+            x = 0
+            loop {
+                x += 1
+                if x > 3 {
+                    break x
+                }
+            }
+            "
+        ),
+        Ok(Value::Integer(4))
+    );
+}
+
+#[test]
+fn iterations_before_break() {
+    assert_eq!(
+        interpret(
+            "
+            list = []
+            loop {
+                list += 'item'
+                if length(list) == 3 {
+                    break length(list)
+                }
+            }
+            "
+        ),
+        Ok(Value::Integer(3))
+    );
+}
+
+#[test]
+fn break_from_inner_loop() {
+    assert_eq!(
+        interpret(
+            "
+            inner = 0
+            outer = 0
+            loop {
+                outer += 1
+                loop {
+                    inner += 1
+                    break inner
+                }
+                if outer == 3 {
+                    break [outer inner]
+                }
+            }
+            "
+        ),
+        Ok(Value::List(List::with_items(vec![
+            Value::Integer(3),
+            Value::Integer(3),
+        ])))
+    );
+}
+
+#[test]
+fn break_outside_loop() {
+    assert!(interpret("break 1").is_err());
+    assert!(interpret("(x <int>) <int> { break x }").is_err());
+    assert!(interpret("loop { f = () <int> { break 1 } break f() }").is_err());
+}
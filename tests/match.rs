@@ -48,3 +48,49 @@ fn match_enum() {
 
     assert_eq!(result, Ok(Value::Boolean(true)));
 }
+
+#[test]
+fn match_wildcard() {
+    let result = interpret(
+        "
+        match 'foo' {
+            'bar' -> 1
+            * -> 2
+        }
+        ",
+    );
+
+    assert_eq!(result, Ok(Value::Integer(2)));
+}
+
+#[test]
+fn exhaustive_boolean_match() {
+    let result = interpret(
+        "
+        match 1 > 2 {
+            true -> 'yes'
+            false -> 'no'
+        }
+        ",
+    );
+
+    assert_eq!(result, Ok(Value::string("no")));
+}
+
+#[test]
+fn non_exhaustive_boolean_match() {
+    let result = interpret(
+        "
+        match 1 > 2 {
+            true -> 'yes'
+        }
+        ",
+    );
+
+    assert!(matches!(
+        result,
+        Err(Error::Validation(
+            error::ValidationError::NonExhaustiveMatch { missing, .. }
+        )) if missing == vec!["false".to_string()]
+    ));
+}
@@ -27,3 +27,52 @@ fn return_statement() {
         Ok(Value::Integer(1))
     );
 }
+
+#[test]
+fn return_values() {
+    assert_eq!(interpret("return 1 + 1"), Ok(Value::Integer(2)));
+    assert_eq!(interpret("return true; false"), Ok(Value::Boolean(true)));
+    assert_eq!(
+        interpret(
+            "
+                f = () <int> { return 5 }
+                return f()
+                6
+                "
+        ),
+        Ok(Value::Integer(5))
+    );
+}
+
+#[test]
+fn bare_return_is_a_syntax_error() {
+    assert!(matches!(
+        interpret("return;"),
+        Err(Error::Syntax(error::SyntaxError::InvalidSource { .. }))
+    ));
+}
+
+#[test]
+fn comments_are_ignored() {
+    assert_eq!(
+        interpret(
+            "
+                # A comment on its own line
+                1 + # a comment between tokens
+                2 # a comment at the end of a line
+                "
+        ),
+        Ok(Value::Integer(3))
+    );
+}
+
+#[test]
+fn check_does_not_run() {
+    assert_eq!(check("panic('ran')"), Ok(()));
+    assert_eq!(check("x = 1 + 2\nx"), Ok(()));
+    assert!(matches!(
+        check("x <str> = 1"),
+        Err(Error::Validation(error::ValidationError::TypeCheck { .. }))
+    ));
+    assert!(matches!(check("return;"), Err(Error::Syntax(_))));
+}
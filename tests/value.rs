@@ -62,6 +62,64 @@ fn string() {
     );
 }
 
+#[test]
+fn string_escape_sequences() {
+    assert_eq!(
+        interpret(r"'a\nb\tc\rd\0'"),
+        Ok(Value::string("a\nb\tc\rd\0".to_string()))
+    );
+    assert_eq!(
+        interpret(r#""\\ \' \`" + '\"'"#),
+        Ok(Value::string("\\ ' `\"".to_string()))
+    );
+    assert_eq!(
+        interpret(r"'\x41\x7e'"),
+        Ok(Value::string("A~".to_string()))
+    );
+    assert_eq!(
+        interpret(r"'\u{48}\u{e9}\u{1F600}'"),
+        Ok(Value::string("Hé😀".to_string()))
+    );
+}
+
+#[test]
+fn invalid_string_escape_sequences() {
+    for source in [
+        r"'\q'",
+        r"'\x4'",
+        r"'\x80'",
+        r"'\u{}'",
+        r"'\u{110000}'",
+        r"'\u{41'",
+        r"'\u41'",
+    ] {
+        assert!(
+            matches!(
+                interpret(source),
+                Err(Error::Syntax(
+                    error::SyntaxError::InvalidEscapeSequence { .. }
+                ))
+            ),
+            "{source}"
+        );
+    }
+
+    assert_eq!(
+        interpret("x = 1\n'ab\\q'"),
+        Err(Error::Syntax(error::SyntaxError::InvalidEscapeSequence {
+            sequence: "\\q".to_string(),
+            position: SourcePosition {
+                start_byte: 9,
+                end_byte: 11,
+                start_row: 2,
+                start_column: 3,
+                end_row: 2,
+                end_column: 5,
+            },
+        }))
+    );
+}
+
 #[test]
 fn list() {
     assert_eq!(
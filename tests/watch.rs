@@ -0,0 +1,29 @@
+use std::{env::temp_dir, fs::write, process::Command};
+
+#[test]
+fn run_once() {
+    let path = temp_dir().join(format!("dust_watch_test_{}.ds", std::process::id()));
+
+    write(&path, "1 + 1").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dust"))
+        .args(["--watch", "--run-once"])
+        .arg(&path)
+        .output()
+        .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "2\n");
+}
+
+#[test]
+fn run_once_requires_watch() {
+    let output = Command::new(env!("CARGO_BIN_EXE_dust"))
+        .args(["--run-once", "file.ds"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+}